@@ -0,0 +1,107 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// The answer to a part, in whichever form its puzzle asks for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer of any width.
+    Int(i128),
+    /// A short string, like the crates on top of day 5's stacks.
+    Str(String),
+    /// An ASCII-art screen, one string per row, for letters to be read off.
+    Screen(Vec<String>),
+}
+
+impl Answer {
+    /// Screen from rows of text, separated by newlines.
+    pub fn screen(rows: &str) -> Self {
+        Answer::Screen(rows.lines().map(str::to_owned).collect())
+    }
+
+    /// Kind of answer, as used in reports.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Str(_) => "string",
+            Answer::Screen(_) => "screen",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Screen(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// Integers as JSON numbers where they fit in an `i64`, otherwise as strings; screens as lists of rows.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            Answer::Str(s) => serializer.serialize_str(s),
+            Answer::Screen(rows) => rows.serialize(serializer),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Int(i128::from(n))
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_display() {
+        let tests = vec![
+            (Answer::from(24000), "24000"),
+            (Answer::from(2713310158_u64), "2713310158"),
+            (Answer::from(-3_i64), "-3"),
+            (Answer::from("CMZ"), "CMZ"),
+            (Answer::screen("#..\n.#.\n"), "#..\n.#."),
+        ];
+        for (answer, expected) in tests {
+            assert_eq!(answer.to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use crate::grid::GridError;
+
+/// Why a puzzle could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well formed, but has no answer.
+    NoSolution(String),
+    /// A puzzle parameter has the wrong type, or an unusable value.
+    Param(String),
+}
+
+/// Malformed input, located by line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column, in characters.
+    pub column: usize,
+    /// The offending line, when known.
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl SolveError {
+    /// Malformed input at a one-based line and column.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        SolveError::Parse(ParseError {
+            line,
+            column,
+            text: None,
+            message: message.into(),
+        })
+    }
+
+    /// Well-formed input without an answer.
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        SolveError::NoSolution(message.into())
+    }
+
+    /// Parameter that cannot be used.
+    pub fn param(message: impl Into<String>) -> Self {
+        SolveError::Param(message.into())
+    }
+
+    /// Attach the text of the offending line, so it can be shown with the error.
+    pub fn with_text(mut self, line: &str) -> Self {
+        if let SolveError::Parse(err) = &mut self {
+            err.text = Some(line.to_owned());
+        }
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "{}", err),
+            SolveError::NoSolution(message) => write!(f, "No solution: {}", message),
+            SolveError::Param(message) => write!(f, "Invalid parameter: {}", message),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid input at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if let Some(text) = &self.text {
+            let gutter = self.line.to_string().len();
+            write!(
+                f,
+                "\n{:gutter$} |\n{} | {}\n{:gutter$} | {:>column$}",
+                "",
+                self.line,
+                text,
+                "",
+                "^",
+                gutter = gutter,
+                column = self.column,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<GridError> for SolveError {
+    fn from(err: GridError) -> Self {
+        match err {
+            GridError::Ragged { row, .. } => SolveError::parse(row + 1, 1, err.to_string()),
+            GridError::InvalidChar { point, found } => SolveError::parse(
+                point.y as usize + 1,
+                point.x as usize + 1,
+                format!("Invalid cell <{}>", found),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_display() {
+        let err = SolveError::parse(12, 3, "Invalid digit <x>").with_text("12x4");
+        assert_eq!(
+            err.to_string(),
+            "Invalid input at line 12, column 3: Invalid digit <x>
+   |
+12 | 12x4
+   |   ^"
+        );
+        assert_eq!(
+            SolveError::no_solution("No marker").to_string(),
+            "No solution: No marker"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Random puzzle inputs from a seed, for stress tests and benchmarks beyond the real input.
+use rand::SeedableRng;
+
+pub use rand::seq::SliceRandom;
+pub use rand::Rng;
+
+/// Random numbers that are the same for a seed on every platform and run.
+pub type Random = rand_chacha::ChaCha8Rng;
+
+/// A day that can make up valid puzzle inputs.
+pub trait Generate {
+    /// Random input that can be solved, with `size` setting how large it is, like its number
+    /// of lines or the side of its grid.
+    fn generate(rng: &mut Random, size: usize) -> String;
+
+    /// Input generated from a seed, always the same for the same seed and size.
+    fn seeded(seed: u64, size: usize) -> String {
+        Self::generate(&mut Random::seed_from_u64(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// Numbers, one per line
+    struct Numbers;
+
+    impl Generate for Numbers {
+        fn generate(rng: &mut Random, size: usize) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.gen_range(0..1000)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_seeded() {
+        assert_eq!(Numbers::seeded(7, 20), Numbers::seeded(7, 20));
+        assert_ne!(Numbers::seeded(7, 20), Numbers::seeded(8, 20));
+        assert_eq!(Numbers::seeded(7, 20).lines().count(), 20);
+    }
+}
@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Argument selecting standard input instead of a file.
+pub const STDIN: &str = "-";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Source for a command line argument, falling back to `default` when absent.
+    pub fn new(arg: Option<&str>, default: impl Into<PathBuf>) -> Self {
+        match arg {
+            Some(STDIN) => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::File(default.into()),
+        }
+    }
+
+    /// Read the whole input.
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => std::fs::read_to_string(path).map_err(|err| {
+                if err.kind() == io::ErrorKind::NotFound {
+                    InputError::Missing(path.to_owned())
+                } else {
+                    InputError::Io(self.clone(), err)
+                }
+            }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError::Io(self.clone(), err))?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Open the input to be read line by line, for inputs too large to hold in memory.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::File(path) => match std::fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    Err(InputError::Missing(path.to_owned()))
+                }
+                Err(err) => Err(InputError::Io(self.clone(), err)),
+            },
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing(PathBuf),
+    /// The input exists but could not be read.
+    Io(Source, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "Input file not found: {} (pass a path, or `-` to read stdin)",
+                path.display()
+            ),
+            InputError::Io(source, err) => write!(f, "Failed to read {}: {}", source, err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing(_) => None,
+            InputError::Io(_, err) => Some(err),
+        }
+    }
+}
+
+/// Byte order mark some editors start a file with.
+const BOM: char = '\u{feff}';
+
+/// Input as every day expects it, whatever editor saved it: without a byte order mark, with `\n`
+/// line endings, and ending with the newline of its last line that is not blank.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let input = match input.contains("\r\n") {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    };
+    let content = input.trim_end().len();
+    if content == 0 {
+        return Cow::Borrowed("");
+    }
+    // Keeping any spaces at the end of the last line, which can matter for grids
+    let end = input[content..]
+        .find('\n')
+        .map_or(input.len(), |n| content + n);
+    if input.len() == end + 1 {
+        return input;
+    }
+    Cow::Owned(format!("{}\n", &input[..end]))
+}
+
+/// A line read on its own, as [`normalise`] leaves it: without its `\n` or `\r\n` line ending,
+/// nor the byte order mark if it is the `first` line, for days reading their input as a stream.
+pub fn normalise_line(line: &str, first: bool) -> &str {
+    let line = match line.strip_suffix('\n') {
+        Some(line) => line.strip_suffix('\r').unwrap_or(line),
+        None => line,
+    };
+    match first {
+        true => line.strip_prefix(BOM).unwrap_or(line),
+        false => line,
+    }
+}
+
+/// Default input of a day crate, `input.txt` next to its `Cargo.toml`.
+pub fn default_path(manifest_dir: impl AsRef<Path>) -> PathBuf {
+    manifest_dir.as_ref().join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_source() {
+        let tests = vec![
+            (None, Source::File(PathBuf::from("day-01/input.txt"))),
+            (Some("-"), Source::Stdin),
+            (Some("other.txt"), Source::File(PathBuf::from("other.txt"))),
+        ];
+        for (arg, expected) in tests {
+            assert_eq!(Source::new(arg, "day-01/input.txt"), expected);
+        }
+    }
+
+    #[test]
+    fn test_missing() {
+        let err = Source::new(None, "does/not/exist.txt").read().unwrap_err();
+        assert!(matches!(err, InputError::Missing(_)));
+        assert_eq!(
+            err.to_string(),
+            "Input file not found: does/not/exist.txt (pass a path, or `-` to read stdin)"
+        );
+        let err = Source::new(None, "does/not/exist.txt")
+            .open()
+            .err()
+            .unwrap();
+        assert!(matches!(err, InputError::Missing(_)));
+    }
+
+    #[test]
+    fn test_normalise() {
+        let tests = vec![
+            ("1\n2\n", "1\n2\n"),
+            ("1\r\n2\r\n", "1\n2\n"),
+            ("\u{feff}1\n2\n", "1\n2\n"),
+            ("1\n2", "1\n2\n"),
+            ("1\n2\n\n\n", "1\n2\n"),
+            ("1\r\n2\r\n  \r\n\r\n", "1\n2\n"),
+            ("  [D]  \n1 2 \n\n", "  [D]  \n1 2 \n"),
+            // Lone carriage returns are not line endings
+            ("1\r2\n", "1\r2\n"),
+            ("\n \n", ""),
+            ("", ""),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(normalise(input), expected);
+        }
+        assert!(matches!(normalise("1\n2\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_normalise_line() {
+        let tests = vec![
+            ("1\n", true, "1"),
+            ("1\r\n", false, "1"),
+            ("\u{feff}1\r\n", true, "1"),
+            ("\u{feff}1\n", false, "\u{feff}1"),
+            ("1 \r", false, "1 \r"),
+            ("2", false, "2"),
+        ];
+        for (line, first, expected) in tests {
+            assert_eq!(normalise_line(line, first), expected);
+        }
+    }
+
+    #[test]
+    fn test_read() {
+        let path = default_path(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            path,
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("input.txt")
+        );
+
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let input = Source::File(manifest).read().unwrap();
+        assert!(input.starts_with("[package]"));
+    }
+}
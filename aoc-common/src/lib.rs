@@ -0,0 +1,180 @@
+use std::path::Path;
+use std::time::Instant;
+
+pub mod answer;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod picture;
+pub mod point;
+pub mod report;
+pub mod simulation;
+pub mod solution;
+pub mod variant;
+
+pub use answer::Answer;
+pub use error::SolveError;
+pub use grid::Grid;
+pub use params::Params;
+pub use point::Point;
+use report::{Format, Report};
+pub use simulation::Simulation;
+pub use solution::Solution;
+
+/// Entry point of the part binaries: solve the input named on the command line and print the answer.
+///
+/// The arguments are an optional input path, or `-` for stdin, any number of
+/// `-P name=value` parameters, which override the day's `params.toml` for this part,
+/// and `--format json` to print a [`Report`](report::Report) instead of the bare answer.
+/// Exits with a non-zero status if the input cannot be read or solved.
+pub fn run<T: Into<Answer>>(
+    manifest_dir: impl AsRef<Path>,
+    part: u8,
+    solve: impl Fn(&str, &Params) -> Result<T, SolveError>,
+) {
+    let manifest_dir = manifest_dir.as_ref();
+    let args = match Args::parse(manifest_dir, part, std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    let start = Instant::now();
+    let answer = match solve(&args.input, &args.params) {
+        Ok(answer) => answer.into(),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    let elapsed = start.elapsed();
+    match args.format {
+        Format::Text => println!("{}", answer),
+        Format::Json => {
+            let day = day_of(manifest_dir).unwrap_or_default();
+            println!("{}", Report::new(day, part, answer, &args.input, elapsed));
+        }
+    }
+}
+
+/// Day of a `day-NN` directory.
+pub fn day_of(dir: &Path) -> Option<u8> {
+    dir.file_name()?
+        .to_str()?
+        .strip_prefix("day-")?
+        .parse()
+        .ok()
+}
+
+/// Command line of a part binary.
+#[derive(Debug)]
+struct Args {
+    input: String,
+    params: Params,
+    format: Format,
+}
+
+impl Args {
+    fn parse(
+        manifest_dir: &Path,
+        part: u8,
+        mut args: impl Iterator<Item = String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut params = Params::load(params::default_path(manifest_dir), part)?;
+        let mut format = Format::default();
+        let mut path = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-P" | "--param" => {
+                    let assignment = args.next().ok_or("Expected name=value after --param")?;
+                    params.set(&assignment)?;
+                }
+                "--format" => {
+                    format = args
+                        .next()
+                        .ok_or("Expected text or json after --format")?
+                        .parse()?;
+                }
+                _ => {
+                    if let Some(assignment) = arg.strip_prefix("--param=") {
+                        params.set(assignment)?;
+                    } else if let Some(name) = arg.strip_prefix("--format=") {
+                        format = name.parse()?;
+                    } else if path.is_none() {
+                        path = Some(arg);
+                    } else {
+                        return Err(format!("Unexpected argument <{}>", arg).into());
+                    }
+                }
+            }
+        }
+        let input =
+            input::Source::new(path.as_deref(), input::default_path(manifest_dir)).read()?;
+        Ok(Self {
+            input,
+            params,
+            format,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_day_of() {
+        assert_eq!(day_of(Path::new("/src/day-07")), Some(7));
+        assert_eq!(day_of(Path::new("/src/aoc-common")), None);
+    }
+
+    #[test]
+    fn test_args() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let args = |args: &[&str]| {
+            Args::parse(manifest_dir, 1, args.iter().map(|arg| arg.to_string()))
+                .map_err(|err| err.to_string())
+        };
+
+        let parsed = args(&["Cargo.toml", "-P", "size=3", "--param=name=abc"]).unwrap();
+        assert!(parsed.input.starts_with("[package]"));
+        assert_eq!(parsed.params.get("size", 0), Ok(3));
+        assert_eq!(
+            parsed.params.get("name", String::new()),
+            Ok("abc".to_string())
+        );
+        assert_eq!(parsed.format, Format::Text);
+        assert_eq!(
+            args(&["--format", "json", "Cargo.toml"]).unwrap().format,
+            Format::Json
+        );
+        assert_eq!(
+            args(&["--format=json", "Cargo.toml"]).unwrap().format,
+            Format::Json
+        );
+        assert_eq!(
+            args(&["Cargo.toml", "--format", "xml"]).unwrap_err(),
+            "Unknown format <xml>, expected text or json"
+        );
+
+        assert_eq!(
+            args(&["Cargo.toml", "--param"]).unwrap_err(),
+            "Expected name=value after --param"
+        );
+        assert_eq!(
+            args(&["Cargo.toml", "-P", "size"]).unwrap_err(),
+            "Invalid parameter <size>, expected name=value"
+        );
+        assert_eq!(
+            args(&["Cargo.toml", "other.txt"]).unwrap_err(),
+            "Unexpected argument <other.txt>"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Reading capital letters off the screens some puzzles draw, in their 4x6 font.
+use std::fmt;
+
+/// Height of a letter, in rows.
+pub const HEIGHT: usize = 6;
+/// Width of a letter, in columns, not counting the blank column after it.
+pub const WIDTH: usize = 4;
+
+/// Every known letter, drawn with `#` for lit pixels.
+const FONT: [(char, [&str; HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Why a screen could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The screen is not as tall as a letter.
+    Height(usize),
+    /// Letters that match no glyph in the font, by the one-based column they start at.
+    Unrecognised(Vec<usize>),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => {
+                write!(f, "Screen is {} rows tall, letters are {}", height, HEIGHT)
+            }
+            OcrError::Unrecognised(columns) => {
+                let columns = columns
+                    .iter()
+                    .map(|column| format!("{}-{}", column, column + WIDTH - 1))
+                    .collect::<Vec<_>>();
+                write!(f, "Unrecognised letters at columns {}", columns.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// Letters spelled out on a screen, where `#` is lit and anything else is dark.
+///
+/// Letters are [`WIDTH`] columns wide with a blank column after each.
+pub fn read<S: AsRef<str>>(rows: &[S]) -> Result<String, OcrError> {
+    if rows.len() != HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+    let rows = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let mut letters = String::new();
+    let mut unrecognised = Vec::new();
+    for start in (0..width).step_by(WIDTH + 1) {
+        let lit = |y: usize, x: usize| rows[y].get(start + x).copied().unwrap_or(false);
+        let glyph = FONT.iter().find(|(_, glyph)| {
+            glyph.iter().enumerate().all(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(x, pixel)| (pixel == '#') == lit(y, x))
+            })
+        });
+        match glyph {
+            Some((letter, _)) => letters.push(*letter),
+            None => unrecognised.push(start + 1),
+        }
+    }
+
+    if unrecognised.is_empty() {
+        Ok(letters)
+    } else {
+        Err(OcrError::Unrecognised(unrecognised))
+    }
+}
+
+/// Screen spelling out the letters, the reverse of [`read`], or `None` if one is not in the font.
+pub fn draw(letters: &str) -> Option<Vec<String>> {
+    let glyphs = letters
+        .chars()
+        .map(|letter| {
+            FONT.iter()
+                .find(|(known, _)| *known == letter)
+                .map(|(_, glyph)| glyph)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let rows = (0..HEIGHT)
+        .map(|y| {
+            glyphs
+                .iter()
+                .map(|glyph| format!("{}.", glyph[y]))
+                .collect()
+        })
+        .collect();
+    Some(rows)
+}
+
+/// Every letter in the font, in alphabetical order.
+pub fn letters() -> impl Iterator<Item = char> {
+    FONT.iter().map(|(letter, _)| *letter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_read() {
+        let screen = [
+            "####.###....##.###..###..#..#..##..#..#.",
+            "#....#..#....#.#..#.#..#.#.#..#..#.#..#.",
+            "###..#..#....#.###..#..#.##...#..#.####.",
+            "#....###.....#.#..#.###..#.#..####.#..#.",
+            "#....#....#..#.#..#.#.#..#.#..#..#.#..#.",
+            "####.#.....##..###..#..#.#..#.#..#.#..#.",
+        ];
+        assert_eq!(read(&screen), Ok("EPJBRKAH".to_string()));
+    }
+
+    #[test]
+    fn test_font() {
+        for (letter, glyph) in FONT {
+            assert_eq!(read(&glyph), Ok(letter.to_string()));
+        }
+        let letters = letters().collect::<String>();
+        assert_eq!(read(&draw(&letters).unwrap()), Ok(letters));
+        assert_eq!(
+            draw("EH"),
+            Some(
+                [
+                    "####.#..#.",
+                    "#....#..#.",
+                    "###..####.",
+                    "#....#..#.",
+                    "#....#..#.",
+                    "####.#..#."
+                ]
+                .map(String::from)
+                .to_vec()
+            )
+        );
+        assert_eq!(draw("EQ"), None);
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        let screen = [
+            "##..##..##",
+            "###...###.",
+            "####....##",
+            "#####.....",
+            "######....",
+            "#######...",
+        ];
+        let err = read(&screen).unwrap_err();
+        assert_eq!(err, OcrError::Unrecognised(vec![1, 6]));
+        assert_eq!(err.to_string(), "Unrecognised letters at columns 1-4, 6-9");
+        assert_eq!(read(&screen[..3]), Err(OcrError::Height(3)));
+    }
+}
@@ -0,0 +1,203 @@
+//! Tunable puzzle parameters, read from a day's `params.toml` and the command line.
+//!
+//! ```toml
+//! # Both parts
+//! entrance = [500, 0]
+//!
+//! # Only part 2, overriding the keys above
+//! [part_2]
+//! marker_size = 20
+//! ```
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+
+use crate::SolveError;
+
+/// Parameters for a single part, by name.
+///
+/// Missing parameters take the default given by the day, so an empty set solves the puzzle as published.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: toml::Table,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// A `params.toml` could not be read or parsed.
+    File(PathBuf, String),
+    /// A command line parameter is not written as `name=value`.
+    Assignment(String),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::File(path, err) => write!(f, "{}: {}", path.display(), err),
+            ParamError::Assignment(arg) => {
+                write!(f, "Invalid parameter <{}>, expected name=value", arg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+impl Params {
+    /// Parameters of a part, numbered from 1, from the contents of a `params.toml`.
+    pub fn from_toml(text: &str, part: u8) -> Result<Self, String> {
+        let mut table = text.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let mut parts = [table.remove("part_1"), table.remove("part_2")];
+        let mut values = table;
+        match parts[usize::from(part) - 1].take() {
+            Some(toml::Value::Table(overrides)) => values.extend(overrides),
+            Some(other) => {
+                return Err(format!(
+                    "part_{} must be a table, found {}",
+                    part,
+                    other.type_str()
+                ))
+            }
+            None => {}
+        }
+        Ok(Self { values })
+    }
+
+    /// Parameters of a part from a `params.toml`, which has none if the file is absent.
+    pub fn load(path: impl AsRef<Path>, part: u8) -> Result<Self, ParamError> {
+        let path = path.as_ref();
+        let error = |err: String| ParamError::File(path.to_owned(), err);
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text, part).map_err(error),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(error(err.to_string())),
+        }
+    }
+
+    /// Set a parameter from a command line argument, like `knot_count=3` or `entrance=[500,0]`.
+    ///
+    /// The value is read as TOML, or taken as a string if it is not valid TOML.
+    pub fn set(&mut self, assignment: &str) -> Result<(), ParamError> {
+        let (name, value) = assignment
+            .split_once('=')
+            .filter(|(name, _)| !name.trim().is_empty())
+            .ok_or_else(|| ParamError::Assignment(assignment.to_owned()))?;
+        let value = format!("value = {}", value.trim())
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.trim().to_owned()));
+        self.values.insert(name.trim().to_owned(), value);
+        Ok(())
+    }
+
+    /// Check that every parameter set is one of `known`, so a misspelt name is not silently ignored.
+    pub fn check(&self, known: &[&str]) -> Result<(), SolveError> {
+        match self
+            .values
+            .keys()
+            .find(|name| !known.contains(&name.as_str()))
+        {
+            Some(name) if known.is_empty() => Err(SolveError::param(format!(
+                "<{}> is not a parameter, this day has none",
+                name
+            ))),
+            Some(name) => Err(SolveError::param(format!(
+                "<{}> is not a parameter, expected {}",
+                name,
+                known.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// A parameter, or `default` if it is not set.
+    pub fn get<T: DeserializeOwned>(&self, name: &str, default: T) -> Result<T, SolveError> {
+        match self.values.get(name) {
+            Some(value) => value.clone().try_into().map_err(|err| {
+                SolveError::param(format!("{} = {}: {}", name, value, err.message()))
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Default parameters file of a day crate, `params.toml` next to its `Cargo.toml`.
+pub fn default_path(manifest_dir: impl AsRef<Path>) -> PathBuf {
+    manifest_dir.as_ref().join("params.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const PARAMS: &str = "
+entrance = [500, 0]
+knot_count = 2
+
+[part_2]
+knot_count = 10
+";
+
+    #[test]
+    fn test_from_toml() {
+        let part_1 = Params::from_toml(PARAMS, 1).unwrap();
+        let part_2 = Params::from_toml(PARAMS, 2).unwrap();
+        assert_eq!(part_1.get("knot_count", 0), Ok(2));
+        assert_eq!(part_2.get("knot_count", 0), Ok(10));
+        assert_eq!(part_2.get("entrance", (0, 0)), Ok((500, 0)));
+        assert_eq!(part_2.get("marker_size", 14), Ok(14));
+        assert_eq!(
+            Params::from_toml("part_1 = 3", 1),
+            Err("part_1 must be a table, found integer".to_string())
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut params = Params::from_toml(PARAMS, 1).unwrap();
+        params.set("knot_count=3").unwrap();
+        params.set("entrance = [10, 2]").unwrap();
+        params.set("name=abc").unwrap();
+        assert_eq!(params.get("knot_count", 0), Ok(3));
+        assert_eq!(params.get("entrance", (0, 0)), Ok((10, 2)));
+        assert_eq!(params.get("name", String::new()), Ok("abc".to_string()));
+        assert_eq!(
+            params.set("knot_count"),
+            Err(ParamError::Assignment("knot_count".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut params = Params::from_toml(PARAMS, 2).unwrap();
+        assert_eq!(params.check(&["entrance", "knot_count"]), Ok(()));
+        params.set("knot_cout=3").unwrap();
+        assert_eq!(
+            params.check(&["entrance", "knot_count"]),
+            Err(SolveError::param(
+                "<knot_cout> is not a parameter, expected entrance, knot_count"
+            ))
+        );
+        assert_eq!(
+            params.check(&[]),
+            Err(SolveError::param(
+                "<entrance> is not a parameter, this day has none"
+            ))
+        );
+        assert_eq!(Params::default().check(&[]), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut params = Params::default();
+        params.set("knot_count=-1").unwrap();
+        assert!(matches!(
+            params.get::<usize>("knot_count", 2),
+            Err(SolveError::Param(message)) if message.starts_with("knot_count = -1: ")
+        ));
+    }
+}
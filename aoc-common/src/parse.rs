@@ -0,0 +1,157 @@
+//! Line-oriented parsing helpers that report failures with their position in the input.
+use std::any::type_name;
+use std::str::FromStr;
+
+use crate::error::SolveError;
+
+/// A line of input and its zero-based index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// One-based line number.
+    pub fn number(&self) -> usize {
+        self.index + 1
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Error pointing at `part`, which should be a slice of this line.
+    pub fn error(&self, part: &str, message: impl Into<String>) -> SolveError {
+        SolveError::parse(self.number(), self.column_of(part), message).with_text(self.text)
+    }
+
+    /// Parse the whole line, ignoring surrounding whitespace.
+    pub fn parse<T: FromStr>(&self) -> Result<T, SolveError> {
+        self.parse_part(self.text.trim())
+    }
+
+    /// Parse a slice of this line.
+    pub fn parse_part<T: FromStr>(&self, part: &str) -> Result<T, SolveError> {
+        part.parse::<T>()
+            .map_err(|_| self.error(part, format!("Invalid {}: <{}>", short_name::<T>(), part)))
+    }
+
+    /// Split on a separator, parsing each piece.
+    pub fn parse_split<T: FromStr>(&self, separator: &str) -> Result<Vec<T>, SolveError> {
+        self.text
+            .split(separator)
+            .map(|part| self.parse_part(part.trim()))
+            .collect()
+    }
+
+    /// One-based column at which `part` starts, or the first column if it is not a slice of this line.
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// Every line of the input, numbered.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Groups of lines separated by blank lines.
+pub fn paragraphs(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut paragraphs = vec![Vec::new()];
+    for line in lines(input) {
+        if line.is_blank() {
+            paragraphs.push(Vec::new());
+        } else {
+            paragraphs.last_mut().unwrap().push(line);
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    paragraphs
+}
+
+/// Error for input that ended while more was expected.
+pub fn unexpected_end(input: &str, expected: &str) -> SolveError {
+    let line = input.lines().count().max(1);
+    let column = input.lines().last().map_or(0, |last| last.chars().count()) + 1;
+    SolveError::parse(
+        line,
+        column,
+        format!("Unexpected end of input, expected {}", expected),
+    )
+}
+
+/// Error at the start of `rest`, the unparsed suffix of `input` left by a parser.
+pub fn error_at(input: &str, rest: &str, message: impl Into<String>) -> SolveError {
+    let offset = input.len().saturating_sub(rest.len());
+    let consumed = &input[..offset];
+    let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+    let line = consumed.matches('\n').count() + 1;
+    let column = consumed[line_start..].chars().count() + 1;
+    let text = input[line_start..].lines().next().unwrap_or_default();
+    SolveError::parse(line, column, message).with_text(text)
+}
+
+/// Type name without its module path, for error messages.
+fn short_name<T>() -> &'static str {
+    let name = type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_parse() {
+        let line = lines("1,2\n3,x").nth(1).unwrap();
+        assert_eq!(line.number(), 2);
+        assert_eq!(
+            line.parse_split::<i32>(","),
+            Err(SolveError::parse(2, 3, "Invalid i32: <x>").with_text("3,x"))
+        );
+        assert_eq!(
+            lines("1,2").next().unwrap().parse_split::<i32>(","),
+            Ok(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let paragraphs = paragraphs("1\n2\n\n3\n\n\n4\n");
+        let numbers = paragraphs
+            .iter()
+            .map(|lines| lines.iter().map(|line| line.number()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![vec![1, 2], vec![4], vec![7]]);
+    }
+
+    #[test]
+    fn test_error_at() {
+        let input = "1-2\n3-x\n";
+        assert_eq!(
+            error_at(input, &input[6..], "Expected a number"),
+            SolveError::parse(2, 3, "Expected a number").with_text("3-x")
+        );
+    }
+
+    #[test]
+    fn test_unexpected_end() {
+        assert_eq!(
+            unexpected_end("ab\ncd", "a move"),
+            SolveError::parse(2, 3, "Unexpected end of input, expected a move")
+        );
+    }
+}
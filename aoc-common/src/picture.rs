@@ -0,0 +1,123 @@
+use crate::Grid;
+
+/// Colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Tiles painted in a few fixed colours.
+pub trait Palette {
+    /// Every colour a tile of this type can have.
+    const COLOURS: &'static [Rgb];
+
+    /// Index of this tile's colour in [`COLOURS`](Palette::COLOURS).
+    fn colour(&self) -> u8;
+}
+
+/// A snapshot that can be painted as an image, for exports.
+pub trait Paint {
+    fn paint(&self) -> Picture;
+}
+
+impl<T: Palette> Paint for Grid<T> {
+    fn paint(&self) -> Picture {
+        Picture::from_grid(self)
+    }
+}
+
+/// Image of one pixel per tile, each an index into a palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    palette: &'static [Rgb],
+    pixels: Vec<u8>,
+}
+
+impl Picture {
+    /// Picture of a grid, painting each tile in its colour.
+    pub fn from_grid<T: Palette>(grid: &Grid<T>) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            palette: T::COLOURS,
+            pixels: grid.iter().map(|(_, tile)| tile.colour()).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn palette(&self) -> &'static [Rgb] {
+        self.palette
+    }
+
+    /// Palette index of every pixel, row by row.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The picture enlarged `scale` times, with each pixel becoming a square.
+    pub fn scaled(&self, scale: usize) -> Picture {
+        let pixels = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|row| {
+                let row = row
+                    .iter()
+                    .flat_map(|pixel| std::iter::repeat_n(*pixel, scale))
+                    .collect::<Vec<_>>();
+                std::iter::repeat_n(row, scale).flatten()
+            })
+            .collect();
+        Picture {
+            width: self.width * scale,
+            height: self.height * scale,
+            palette: self.palette,
+            pixels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Off,
+        On,
+    }
+
+    impl Palette for Cell {
+        const COLOURS: &'static [Rgb] = &[[0, 0, 0], [255, 255, 255]];
+
+        fn colour(&self) -> u8 {
+            *self as u8
+        }
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::parse("#.\n.#\n..", |c| match c {
+            '#' => Some(Cell::On),
+            '.' => Some(Cell::Off),
+            _ => None,
+        })
+        .unwrap();
+        let picture = Picture::from_grid(&grid);
+        assert_eq!((picture.width(), picture.height()), (2, 3));
+        assert_eq!(picture.pixels(), &[1, 0, 0, 1, 0, 0]);
+        assert_eq!(picture.palette(), Cell::COLOURS);
+
+        let scaled = picture.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 6));
+        assert_eq!(&scaled.pixels()[..8], &[1, 1, 0, 0, 1, 1, 0, 0]);
+        assert_eq!(&scaled.pixels()[8..16], &[0, 0, 1, 1, 0, 0, 1, 1]);
+    }
+}
@@ -0,0 +1,162 @@
+//! Machine-readable results, one JSON object per solved part.
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::Answer;
+
+/// How solved parts are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// The answer alone, for people.
+    #[default]
+    Text,
+    /// A [`Report`] per part, as a line of JSON.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format <{}>, expected text or json", s)),
+        }
+    }
+}
+
+/// A solved part, with enough detail to tell whether a later run changed anything.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub answer_type: &'static str,
+    /// SHA-256 of the input, in hex.
+    pub input_hash: String,
+    /// Time to parse the input and compute the answer, in nanoseconds.
+    pub elapsed_ns: u64,
+}
+
+impl Report {
+    pub fn new(day: u8, part: u8, answer: Answer, input: &str, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer_type: answer.kind(),
+            answer,
+            input_hash: input_hash(input),
+            elapsed_ns: u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// The report as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialize")
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+/// A part that was not solved, printed in place of its [`Report`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Failure {
+    pub day: u8,
+    pub part: u8,
+    pub error: String,
+    /// Whether the part panicked, rather than returning an error.
+    pub panicked: bool,
+}
+
+impl Failure {
+    pub fn new(day: u8, part: u8, error: impl fmt::Display, panicked: bool) -> Self {
+        Self {
+            day,
+            part,
+            error: error.to_string(),
+            panicked,
+        }
+    }
+
+    /// The failure as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("failures always serialize")
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+/// SHA-256 of an input, in hex.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert_eq!(
+            "xml".parse::<Format>(),
+            Err("Unknown format <xml>, expected text or json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let tests = vec![
+            (
+                Report::new(1, 2, Answer::from(45000), "", Duration::from_micros(12)),
+                r#"{"day":1,"part":2,"answer":45000,"answer_type":"int","input_hash":"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855","elapsed_ns":12000}"#,
+            ),
+            (
+                Report::new(5, 1, Answer::from("CMZ"), "", Duration::ZERO),
+                r#"{"day":5,"part":1,"answer":"CMZ","answer_type":"string","input_hash":"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855","elapsed_ns":0}"#,
+            ),
+            (
+                Report::new(10, 2, Answer::screen("#.\n.#"), "", Duration::ZERO),
+                r##"{"day":10,"part":2,"answer":["#.",".#"],"answer_type":"screen","input_hash":"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855","elapsed_ns":0}"##,
+            ),
+        ];
+        for (report, expected) in tests {
+            assert_eq!(report.to_json(), expected);
+        }
+    }
+
+    #[test]
+    fn test_failure() {
+        assert_eq!(
+            Failure::new(8, 1, "No solution: Empty forest", false).to_json(),
+            r#"{"day":8,"part":1,"error":"No solution: Empty forest","panicked":false}"#
+        );
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
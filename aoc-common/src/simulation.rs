@@ -0,0 +1,134 @@
+//! Puzzles solved one discrete step at a time, so tooling can trace, replay or draw any of them.
+use crate::Point;
+
+/// A state advanced one step at a time, like a falling unit of sand or a CPU cycle.
+pub trait Simulation {
+    /// Copy of the state at a single step.
+    type Snapshot: Clone;
+
+    /// Advance one step, or return `false` without changing anything once finished.
+    fn step(&mut self) -> bool;
+
+    /// Number of steps taken so far.
+    fn steps(&self) -> usize;
+
+    /// Copy of the current state.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Step until `done` holds or the simulation finishes, returning the number of steps taken.
+    fn run_until(&mut self, mut done: impl FnMut(&Self) -> bool) -> usize
+    where
+        Self: Sized,
+    {
+        let start = self.steps();
+        while !done(self) && self.step() {}
+        self.steps() - start
+    }
+
+    /// Step until the simulation finishes, which some never do.
+    fn run(&mut self) -> usize
+    where
+        Self: Sized,
+    {
+        self.run_until(|_| false)
+    }
+
+    /// The current snapshot, followed by one after every step until the simulation finishes.
+    fn snapshots(&mut self) -> Snapshots<'_, Self>
+    where
+        Self: Sized,
+    {
+        Snapshots {
+            simulation: self,
+            started: false,
+        }
+    }
+}
+
+/// Iterator over the snapshots of a simulation as it runs, see [`Simulation::snapshots`].
+#[derive(Debug)]
+pub struct Snapshots<'a, S> {
+    simulation: &'a mut S,
+    started: bool,
+}
+
+impl<S: Simulation> Iterator for Snapshots<'_, S> {
+    type Item = S::Snapshot;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.started && !self.simulation.step() {
+            return None;
+        }
+        self.started = true;
+        Some(self.simulation.snapshot())
+    }
+}
+
+/// A snapshot that can be drawn as text, for animations.
+pub trait Draw {
+    fn draw(&self) -> Frame;
+}
+
+/// Text drawing of a snapshot, placed in coordinates that stay the same from frame to frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub rows: Vec<String>,
+    /// Position of the first character of the first row.
+    pub origin: Point,
+    /// Where the action is, for a viewport to follow.
+    pub focus: Point,
+    /// One line describing the state, like the step it is at.
+    pub caption: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// Counts down to zero
+    struct Countdown {
+        value: u32,
+        steps: usize,
+    }
+
+    impl Simulation for Countdown {
+        type Snapshot = u32;
+
+        fn step(&mut self) -> bool {
+            if self.value == 0 {
+                return false;
+            }
+            self.value -= 1;
+            self.steps += 1;
+            true
+        }
+
+        fn steps(&self) -> usize {
+            self.steps
+        }
+
+        fn snapshot(&self) -> u32 {
+            self.value
+        }
+    }
+
+    #[test]
+    fn test_run_until() {
+        let mut countdown = Countdown { value: 5, steps: 0 };
+        assert_eq!(countdown.run_until(|countdown| countdown.value == 3), 2);
+        assert_eq!(countdown.snapshot(), 3);
+        assert_eq!(countdown.run(), 3);
+        assert_eq!(countdown.steps(), 5);
+        assert!(!countdown.step());
+        assert_eq!(countdown.steps(), 5);
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let mut countdown = Countdown { value: 3, steps: 0 };
+        assert_eq!(countdown.snapshots().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(countdown.snapshots().collect::<Vec<_>>(), vec![0]);
+    }
+}
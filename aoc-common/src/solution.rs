@@ -0,0 +1,73 @@
+use crate::input::normalise;
+use crate::variant::Variant;
+use crate::{Answer, Params, SolveError};
+
+/// A day's puzzle: the input is parsed once, and both parts are answered from it.
+pub trait Solution {
+    /// The parsed input, which may borrow from the input text.
+    type Input<'a>;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    /// Names of the parameters the parts read, any other being rejected.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne, SolveError>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo, SolveError>;
+
+    /// Answer part one with tunable puzzle parameters, for days that have any.
+    fn part_one_with(
+        input: &Self::Input<'_>,
+        _params: &Params,
+    ) -> Result<Self::PartOne, SolveError> {
+        Self::part_one(input)
+    }
+
+    /// Answer part two with tunable puzzle parameters, for days that have any.
+    fn part_two_with(
+        input: &Self::Input<'_>,
+        _params: &Params,
+    ) -> Result<Self::PartTwo, SolveError> {
+        Self::part_two(input)
+    }
+
+    /// Parse the input and answer part one.
+    fn solve_part_one(input: &str) -> Result<Self::PartOne, SolveError> {
+        Self::part_one(&Self::parse(&normalise(input))?)
+    }
+
+    /// Parse the input and answer part two.
+    fn solve_part_two(input: &str) -> Result<Self::PartTwo, SolveError> {
+        Self::part_two(&Self::parse(&normalise(input))?)
+    }
+
+    /// Parse the input and answer part one, with parameters.
+    fn solve_part_one_with(input: &str, params: &Params) -> Result<Self::PartOne, SolveError> {
+        params.check(Self::PARAMS)?;
+        Self::part_one_with(&Self::parse(&normalise(input))?, params)
+    }
+
+    /// Parse the input and answer part two, with parameters.
+    fn solve_part_two_with(input: &str, params: &Params) -> Result<Self::PartTwo, SolveError> {
+        params.check(Self::PARAMS)?;
+        Self::part_two_with(&Self::parse(&normalise(input))?, params)
+    }
+
+    /// Parse the input once, and answer both parts.
+    fn solve(input: &str) -> Result<(Self::PartOne, Self::PartTwo), SolveError> {
+        let input = normalise(input);
+        let input = Self::parse(&input)?;
+        Ok((Self::part_one(&input)?, Self::part_two(&input)?))
+    }
+
+    /// Other implementations of the parts, like a faster one, to be cross-checked against these.
+    fn variants() -> Vec<Variant<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
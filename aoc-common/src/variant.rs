@@ -0,0 +1,177 @@
+//! Other implementations of a day's parts, like a faster one, cross-checked against its [`Solution`].
+use std::fmt;
+
+use crate::input::normalise;
+use crate::{Answer, Params, Solution, SolveError};
+
+/// A part implemented another way, answering from the same parsed input.
+pub type Part<S, T> = for<'a> fn(&<S as Solution>::Input<'a>, &Params) -> Result<T, SolveError>;
+
+/// Another implementation of one or both of a day's parts.
+pub struct Variant<S: Solution> {
+    pub name: &'static str,
+    pub part_one: Option<Part<S, S::PartOne>>,
+    pub part_two: Option<Part<S, S::PartTwo>>,
+}
+
+/// Answers of a part from a day's own [`Solution`] and every variant implementing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub part: u8,
+    /// By name, starting with the solution's own.
+    pub answers: Vec<(&'static str, Result<Answer, SolveError>)>,
+}
+
+impl Check {
+    /// Name of the answer from the day's [`Solution`].
+    pub const SOLUTION: &'static str = "solution";
+
+    /// Whether any variant implements the part, so there was something to compare.
+    pub fn compared(&self) -> bool {
+        self.answers.len() > 1
+    }
+
+    /// Whether every implementation gave the same answer, or every one failed, whatever the error.
+    pub fn agrees(&self) -> bool {
+        self.answers
+            .windows(2)
+            .all(|pair| pair[0].1.as_ref().ok() == pair[1].1.as_ref().ok())
+    }
+}
+
+/// Each implementation's answer, one per line.
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, answer)) in self.answers.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            match answer {
+                Ok(answer @ Answer::Screen(_)) => write!(f, "{}:\n{}", name, answer)?,
+                Ok(answer) => write!(f, "{}: {}", name, answer)?,
+                Err(err) => write!(f, "{}: failed, {}", name, err)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse the input once, and answer a part, numbered from 1, with the solution and each of its variants.
+pub fn check<S: Solution>(part: u8, input: &str, params: &Params) -> Result<Check, SolveError> {
+    let input = normalise(input);
+    let parsed = S::parse(&input)?;
+    let answer = match part {
+        1 => S::part_one_with(&parsed, params).map(Into::into),
+        _ => S::part_two_with(&parsed, params).map(Into::into),
+    };
+    let mut answers = vec![(Check::SOLUTION, answer)];
+    for variant in S::variants() {
+        let answer = match part {
+            1 => variant
+                .part_one
+                .map(|part_one| part_one(&parsed, params).map(Into::into)),
+            _ => variant
+                .part_two
+                .map(|part_two| part_two(&parsed, params).map(Into::into)),
+        };
+        if let Some(answer) = answer {
+            answers.push((variant.name, answer));
+        }
+    }
+    Ok(Check { part, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    /// Sum of the numbers, and the largest
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input<'a> = Vec<i32>;
+        type PartOne = i32;
+        type PartTwo = i32;
+
+        fn parse(input: &str) -> Result<Vec<i32>, SolveError> {
+            input
+                .split_whitespace()
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| SolveError::parse(1, 1, "Not a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(numbers: &Vec<i32>) -> Result<i32, SolveError> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part_two(numbers: &Vec<i32>) -> Result<i32, SolveError> {
+            numbers
+                .iter()
+                .copied()
+                .max()
+                .ok_or_else(|| SolveError::no_solution("No numbers"))
+        }
+
+        fn variants() -> Vec<Variant<Self>> {
+            vec![
+                Variant {
+                    name: "loop",
+                    part_one: Some(|numbers, _| {
+                        let mut sum = 0;
+                        for n in numbers {
+                            sum += n;
+                        }
+                        Ok(sum)
+                    }),
+                    part_two: None,
+                },
+                Variant {
+                    name: "first",
+                    part_one: None,
+                    // Wrong unless the largest comes first
+                    part_two: Some(|numbers, _| {
+                        numbers
+                            .first()
+                            .copied()
+                            .ok_or_else(|| SolveError::no_solution("Empty"))
+                    }),
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let params = Params::default();
+        let checked = check::<Numbers>(1, "3 1 2", &params).unwrap();
+        assert_eq!(
+            checked.answers,
+            vec![
+                ("solution", Ok(Answer::Int(6))),
+                ("loop", Ok(Answer::Int(6)))
+            ]
+        );
+        assert!(checked.compared() && checked.agrees());
+
+        let checked = check::<Numbers>(2, "3 1 2", &params).unwrap();
+        assert!(checked.agrees());
+        let checked = check::<Numbers>(2, "1 3 2", &params).unwrap();
+        assert!(!checked.agrees());
+        assert_eq!(checked.to_string(), "solution: 3\nfirst: 1");
+
+        // Failing differently still agrees
+        let checked = check::<Numbers>(2, "", &params).unwrap();
+        assert!(checked.agrees());
+        assert_eq!(
+            checked.to_string(),
+            "solution: failed, No solution: No numbers\nfirst: failed, No solution: Empty"
+        );
+
+        assert!(check::<Numbers>(1, "x", &params).is_err());
+    }
+}
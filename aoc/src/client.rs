@@ -0,0 +1,135 @@
+use std::fmt;
+
+use crate::Error;
+
+/// Year of every puzzle in the workspace.
+pub const YEAR: u16 = 2022;
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The HTTP the client needs, so it can be swapped out or pointed elsewhere.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, Error>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, Error>;
+}
+
+/// Blocking HTTP, over `ureq`.
+pub struct Ureq(ureq::Agent);
+
+impl Default for Ureq {
+    fn default() -> Self {
+        Self(
+            ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        )
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, Error> {
+        self.0
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(http_error)?
+            .into_string()
+            .map_err(|err| Error::Http(err.to_string()))
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.0
+            .post(url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(form)
+            .map_err(http_error)?
+            .into_string()
+            .map_err(|err| Error::Http(err.to_string()))
+    }
+}
+
+fn http_error(err: ureq::Error) -> Error {
+    match err {
+        ureq::Error::Status(status, response) => {
+            Error::Http(format!("{} returned {}", response.get_url(), status))
+        }
+        err => Error::Http(err.to_string()),
+    }
+}
+
+/// Downloads inputs from, and submits answers to, Advent of Code.
+pub struct Client<H = Ureq> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// A day's puzzle input.
+    pub fn input(&self, day: u8) -> Result<String, Error> {
+        self.http.get(&self.url(day, "input"), &self.session)
+    }
+
+    /// Submit the answer to a part, numbered from 1.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Submission, Error> {
+        let level = part.to_string();
+        let page = self.http.post_form(
+            &self.url(day, "answer"),
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Ok(Submission::from_page(&page))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, YEAR, day, path)
+    }
+}
+
+/// How Advent of Code responded to an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect,
+    /// Answers were submitted too recently, and have to wait.
+    TooSoon,
+    /// The part was already solved, or is not unlocked yet.
+    AlreadyCompleted,
+    /// A response that is not recognised, with the page's text.
+    Unknown(String),
+}
+
+impl Submission {
+    fn from_page(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Submission::Correct
+        } else if page.contains("That's not the right answer") {
+            Submission::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Submission::TooSoon
+        } else if page.contains("Did you already complete it") {
+            Submission::AlreadyCompleted
+        } else {
+            Submission::Unknown(page.to_string())
+        }
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer"),
+            Submission::Incorrect => write!(f, "That's not the right answer"),
+            Submission::TooSoon => write!(f, "Answered too recently, try again later"),
+            Submission::AlreadyCompleted => write!(f, "Already completed, or not unlocked yet"),
+            Submission::Unknown(page) => write!(f, "Unrecognised response:\n{}", page),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub mod client;
+pub mod scaffold;
+
+pub use aoc_common::day_of;
+
+/// Why a helper command failed.
+#[derive(Debug)]
+pub enum Error {
+    /// A file the command would create is already there.
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+    /// The workspace `Cargo.toml` could not be understood.
+    Manifest(String),
+    /// The runner's `Cargo.toml` or `with_days!` list could not be understood.
+    Runner(String),
+    /// No day was given, and none could be worked out from the current directory.
+    UnknownDay,
+    /// The session cookie for adventofcode.com is not set.
+    MissingSession,
+    Http(String),
+    /// A day's `answers.toml` could not be read.
+    Answers(String),
+    /// A day is not registered in the runner, or could not be solved.
+    Solve(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Exists(path) => write!(f, "{} already exists", path.display()),
+            Error::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::Manifest(message) => write!(f, "Workspace Cargo.toml: {}", message),
+            Error::Runner(message) => write!(f, "Runner: {}", message),
+            Error::UnknownDay => write!(f, "Pass --day, or run inside a day-NN directory"),
+            Error::MissingSession => write!(
+                f,
+                "Set AOC_SESSION to the session cookie of adventofcode.com, or pass --session"
+            ),
+            Error::Http(message) => write!(f, "{}", message),
+            Error::Answers(message) => write!(f, "{}", message),
+            Error::Solve(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Directory of a day's crate, e.g. `day-07`.
+pub fn day_dir(day: u8) -> String {
+    format!("day-{:02}", day)
+}
+
+/// Read a file, keeping its path for the error.
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|err| Error::Io(path.to_owned(), err))
+}
+
+/// Write a file, refusing to replace an existing one.
+fn create(path: &Path, contents: &str) -> Result<(), Error> {
+    if path.exists() {
+        return Err(Error::Exists(path.to_owned()));
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| Error::Io(parent.to_owned(), err))?;
+    }
+    std::fs::write(path, contents).map_err(|err| Error::Io(path.to_owned(), err))
+}
@@ -0,0 +1,191 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand};
+
+use aoc::client::{Client, Submission, Ureq, BASE_URL};
+use aoc::{day_dir, day_of, scaffold, Error};
+use aoc_common::{Answer, Params};
+use runner::answers::Answers;
+
+/// Helpers for working on Advent of Code 2022 in this workspace
+#[derive(Parser, Debug)]
+#[command(version)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create a new day crate, with part 1, and add it to the workspace
+    Day {
+        /// Day to create [default: the day after the last one]
+        day: Option<u8>,
+    },
+    /// Copy part 1 of a day to part 2
+    Part {
+        #[command(flatten)]
+        day: DayArg,
+    },
+    /// Download a day's input.txt
+    Input {
+        #[command(flatten)]
+        day: DayArg,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Submit the first part of a day without a recorded answer, recording it if it is right
+    Submit {
+        #[command(flatten)]
+        day: DayArg,
+        #[command(flatten)]
+        remote: Remote,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+struct DayArg {
+    /// Day to work on [default: the day-NN directory you are in]
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(clap::Args, Debug)]
+struct Remote {
+    /// Session cookie of adventofcode.com
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Server to talk to, e.g. a local mock
+    #[arg(long, env = "AOC_BASE_URL", default_value = BASE_URL)]
+    base_url: String,
+}
+
+impl DayArg {
+    fn resolve(&self) -> Result<u8, Error> {
+        match self.day {
+            Some(day) => Ok(day),
+            None => std::env::current_dir()
+                .ok()
+                .and_then(|dir| day_of(&dir))
+                .ok_or(Error::UnknownDay),
+        }
+    }
+}
+
+impl Remote {
+    fn client(&self) -> Result<Client, Error> {
+        let session = self.session.clone().ok_or(Error::MissingSession)?;
+        Ok(Client::new(Ureq::default(), &self.base_url, session))
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+    if let Err(err) = run(args.command) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(command: Command) -> Result<(), Error> {
+    let workspace = workspace()?;
+    match command {
+        Command::Day { day } => {
+            let day = match day {
+                Some(day) => day,
+                None => scaffold::next_day(&workspace)?,
+            };
+            let dir = scaffold::day(&workspace, day)?;
+            println!("Created {}", dir.display());
+        }
+        Command::Part { day } => {
+            let dir = workspace.join(day_dir(day.resolve()?));
+            scaffold::part(&dir)?;
+            println!("Copied part 1 to part 2 in {}", dir.display());
+        }
+        Command::Input { day, remote } => {
+            let path = workspace.join(day_dir(day.resolve()?)).join("input.txt");
+            if path.exists() {
+                return Err(Error::Exists(path));
+            }
+            let input = remote.client()?.input(day.resolve()?)?;
+            std::fs::write(&path, input).map_err(|err| Error::Io(path.clone(), err))?;
+            println!("Downloaded {}", path.display());
+        }
+        Command::Submit { day, remote } => submit(day.resolve()?, &remote)?,
+    }
+    Ok(())
+}
+
+/// Submit the first unanswered part, recording the answer when it is right.
+fn submit(number: u8, remote: &Remote) -> Result<(), Error> {
+    let day = runner::registry::day(number).ok_or_else(|| {
+        Error::Solve(format!(
+            "Day {:02} is not registered in the runner, so it cannot be solved",
+            number
+        ))
+    })?;
+    let answers_path = day.answers_path();
+    let answers = Answers::load(&answers_path).map_err(Error::Answers)?;
+    let Some(part) = (1..=2).find(|part| answers.get(*part).is_none()) else {
+        println!("Both parts of day {:02} are already answered", number);
+        return Ok(());
+    };
+
+    let input_path = day.input_path();
+    let input = std::fs::read_to_string(&input_path).map_err(|err| Error::Io(input_path, err))?;
+    let answer = day
+        .solve(part, &input, &Params::default())
+        .map_err(|err| Error::Solve(err.to_string()))?
+        .answer;
+
+    if let Answer::Screen(_) = answer {
+        return Err(Error::Solve(format!(
+            "Day {:02} Part {} answers with a screen, submit the letters on it by hand:\n{}",
+            number, part, answer
+        )));
+    }
+
+    println!("Day {:02} Part {}: {}", number, part, answer);
+    let submission = remote.client()?.submit(number, part, &answer.to_string())?;
+    println!("{}", submission);
+    if submission == Submission::Correct {
+        record(&answers_path, part, &answer)?;
+    }
+    Ok(())
+}
+
+/// Add a part's answer to an `answers.toml`, as an integer where possible.
+fn record(path: &Path, part: u8, answer: &Answer) -> Result<(), Error> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(Error::Io(path.to_owned(), err)),
+    };
+    let mut answers = text
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|err| Error::Answers(format!("{}: {}", path.display(), err)))?;
+    let key = format!("part_{}", part);
+    answers[key.as_str()] = match answer {
+        // TOML integers are 64-bit, so anything wider is kept as a string
+        Answer::Int(n) => match i64::try_from(*n) {
+            Ok(n) => toml_edit::value(n),
+            Err(_) => toml_edit::value(n.to_string()),
+        },
+        answer => toml_edit::value(answer.to_string()),
+    };
+    std::fs::write(path, answers.to_string()).map_err(|err| Error::Io(path.to_owned(), err))
+}
+
+/// The nearest directory, from the current one up, with a workspace `Cargo.toml`.
+fn workspace() -> Result<PathBuf, Error> {
+    let current = std::env::current_dir().map_err(|err| Error::Io(PathBuf::from("."), err))?;
+    current
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| Error::Manifest("not inside a Cargo workspace".to_string()))
+}
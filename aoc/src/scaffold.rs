@@ -0,0 +1,256 @@
+use std::path::{Path, PathBuf};
+
+use crate::{create, day_dir, day_of, read, Error};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const GENERATE: &str = include_str!("../templates/generate.rs.tmpl");
+const LIB: &str = include_str!("../templates/lib.rs.tmpl");
+const PART: &str = include_str!("../templates/part.rs.tmpl");
+const UNSOLVED: &str = include_str!("../templates/unsolved.rs.tmpl");
+const BIN: &str = include_str!("../templates/bin.rs.tmpl");
+
+/// The day after the highest `day-NN` crate in the workspace.
+pub fn next_day(workspace: &Path) -> Result<u8, Error> {
+    let entries = std::fs::read_dir(workspace).map_err(|err| Error::Io(workspace.into(), err))?;
+    let last = entries
+        .filter_map(|entry| day_of(&entry.ok()?.path()))
+        .max()
+        .unwrap_or(0);
+    Ok(last + 1)
+}
+
+/// Create a `day-NN` crate solving part 1, and add it to the workspace members and the runner.
+pub fn day(workspace: &Path, day: u8) -> Result<PathBuf, Error> {
+    let name = day_dir(day);
+    let dir = workspace.join(&name);
+    if dir.exists() {
+        return Err(Error::Exists(dir));
+    }
+
+    let render = |template: &str| {
+        template
+            .replace("{{crate}}", &name)
+            .replace("{{module}}", &name.replace('-', "_"))
+            .replace("{{solution}}", &format!("Day{:02}", day))
+            .replace("{{day}}", &day.to_string())
+    };
+    create(&dir.join("Cargo.toml"), &render(CARGO_TOML))?;
+    create(&dir.join("src/lib.rs"), &render(LIB))?;
+    create(&dir.join("src/generate.rs"), &render(GENERATE))?;
+    create(&dir.join("src/part_1.rs"), &render(PART))?;
+    create(&dir.join("src/part_2.rs"), UNSOLVED)?;
+    create(&dir.join("src/bin/part_1.rs"), &render(BIN))?;
+    add_member(workspace, &name)?;
+    add_dependency(&workspace.join("runner/Cargo.toml"), &name)?;
+    add_to_registry(&workspace.join("runner/src/registry.rs"), day)?;
+
+    Ok(dir)
+}
+
+/// Start part 2 of a day from a copy of part 1, and add its binary.
+pub fn part(dir: &Path) -> Result<(), Error> {
+    let src = dir.join("src");
+    let part_2 = src.join("part_2.rs");
+    // Only replace the placeholder left by `day`
+    if part_2.exists() && read(&part_2)? != UNSOLVED {
+        return Err(Error::Exists(part_2));
+    }
+    let bin_2 = src.join("bin/part_2.rs");
+    if bin_2.exists() {
+        return Err(Error::Exists(bin_2));
+    }
+
+    let part_1 = read(&src.join("part_1.rs"))?;
+    let part_1 = part_1.replace("solve_part_one", "solve_part_two");
+    std::fs::write(&part_2, part_1).map_err(|err| Error::Io(part_2, err))?;
+    let bin = read(&src.join("bin/part_1.rs"))?;
+    let bin = bin
+        .replace("solve_part_one", "solve_part_two")
+        .replace("\"), 1, ", "\"), 2, ");
+    create(&bin_2, &bin)
+}
+
+/// Add a crate to the workspace members, before the first member that sorts after it.
+fn add_member(workspace: &Path, member: &str) -> Result<(), Error> {
+    let path = workspace.join("Cargo.toml");
+    let mut manifest = read(&path)?
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|err| Error::Manifest(err.to_string()))?;
+    let members = manifest
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("members"))
+        .and_then(|members| members.as_array_mut())
+        .ok_or_else(|| Error::Manifest("missing a workspace.members list".to_string()))?;
+
+    if members
+        .iter()
+        .any(|existing| existing.as_str() == Some(member))
+    {
+        return Ok(());
+    }
+    let index = members
+        .iter()
+        .position(|existing| existing.as_str().is_some_and(|existing| existing > member))
+        .unwrap_or(members.len());
+    members.insert(index, member);
+
+    std::fs::write(&path, manifest.to_string()).map_err(|err| Error::Io(path, err))
+}
+
+/// Add a day's crate to the runner's dependencies, which are kept sorted.
+fn add_dependency(path: &Path, name: &str) -> Result<(), Error> {
+    let mut manifest = read(path)?
+        .parse::<toml_edit::DocumentMut>()
+        .map_err(|err| Error::Runner(err.to_string()))?;
+    let dependencies = manifest
+        .get_mut("dependencies")
+        .and_then(|dependencies| dependencies.as_table_mut())
+        .ok_or_else(|| Error::Runner("missing a [dependencies] table".to_string()))?;
+
+    if dependencies.contains_key(name) {
+        return Ok(());
+    }
+    let mut dependency = toml_edit::InlineTable::new();
+    dependency.insert("path", format!("../{}", name).into());
+    dependency.fmt();
+    dependencies.insert(name, toml_edit::value(dependency));
+    dependencies.sort_values();
+
+    std::fs::write(path, manifest.to_string()).map_err(|err| Error::Io(path.to_owned(), err))
+}
+
+/// Add a day to the runner's `with_days!` list, before the first day after it.
+fn add_to_registry(path: &Path, day: u8) -> Result<(), Error> {
+    let registry = read(path)?;
+    let name = day_dir(day);
+    let entry = format!(
+        "{}, \"{}\" => {}::Day{:02},",
+        day,
+        name,
+        name.replace('-', "_"),
+        day
+    );
+    if registry.lines().any(|line| line.trim() == entry) {
+        return Ok(());
+    }
+
+    // Lines like `7, "day-07" => day_07::Day07,`, with their day
+    let days = registry
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (number, _) = line.trim().split_once(", \"day-")?;
+            Some((index, number.parse::<u8>().ok()?, line))
+        })
+        .collect::<Vec<_>>();
+    let &(last, _, line) = days
+        .last()
+        .ok_or_else(|| Error::Runner("no days in with_days!".to_string()))?;
+    let index = days
+        .iter()
+        .find(|(_, number, _)| *number > day)
+        .map_or(last + 1, |&(index, _, _)| index);
+    let indent = &line[..line.len() - line.trim_start().len()];
+
+    let mut lines = registry.lines().map(str::to_string).collect::<Vec<_>>();
+    lines.insert(index, format!("{}{}", indent, entry));
+    let registry = lines.join("\n") + "\n";
+    std::fs::write(path, registry).map_err(|err| Error::Io(path.to_owned(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = [
+  "aoc-common",
+  "day-01",
+  "runner",
+]
+"#;
+
+    const RUNNER: &str = r#"[package]
+name = "runner"
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+day-01 = { path = "../day-01" }
+toml = "0.8"
+"#;
+
+    const REGISTRY: &str = r#"macro_rules! with_days {
+    ($callback:ident) => {
+        $callback! {
+            1, "day-01" => day_01::Day01,
+        }
+    };
+}
+"#;
+
+    #[test]
+    fn test_day_and_part() {
+        let workspace = tempfile::tempdir().unwrap();
+        let root = workspace.path();
+        std::fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        std::fs::create_dir(root.join("day-01")).unwrap();
+        std::fs::create_dir_all(root.join("runner/src")).unwrap();
+        std::fs::write(root.join("runner/Cargo.toml"), RUNNER).unwrap();
+        std::fs::write(root.join("runner/src/registry.rs"), REGISTRY).unwrap();
+
+        assert_eq!(next_day(root).unwrap(), 2);
+        let dir = day(root, 2).unwrap();
+        assert_eq!(dir, root.join("day-02"));
+        assert_eq!(next_day(root).unwrap(), 3);
+
+        let manifest = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(
+            manifest,
+            r#"[workspace]
+resolver = "2"
+members = [
+  "aoc-common",
+  "day-01", "day-02",
+  "runner",
+]
+"#
+        );
+        let runner = std::fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+        assert_eq!(
+            runner,
+            r#"[package]
+name = "runner"
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+day-01 = { path = "../day-01" }
+day-02 = { path = "../day-02" }
+toml = "0.8"
+"#
+        );
+        let registry = std::fs::read_to_string(root.join("runner/src/registry.rs")).unwrap();
+        assert!(registry.contains(
+            "            1, \"day-01\" => day_01::Day01,\n            2, \"day-02\" => day_02::Day02,\n        }"
+        ));
+        let cargo = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"day-02\""));
+        let bin = std::fs::read_to_string(dir.join("src/bin/part_1.rs")).unwrap();
+        assert!(bin.contains("use day_02::Day02;"));
+        assert!(bin.contains("1, Day02::solve_part_one_with"));
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day02 {"));
+        let generate = std::fs::read_to_string(dir.join("src/generate.rs")).unwrap();
+        assert!(generate.contains("impl Generate for Day02 {"));
+        assert!(matches!(day(root, 2), Err(Error::Exists(_))));
+
+        part(&dir).unwrap();
+        let bin = std::fs::read_to_string(dir.join("src/bin/part_2.rs")).unwrap();
+        assert!(bin.contains("2, Day02::solve_part_two_with"));
+        let part_2 = std::fs::read_to_string(dir.join("src/part_2.rs")).unwrap();
+        assert!(part_2.contains("Day02::solve_part_two(input)"));
+        assert!(matches!(part(&dir), Err(Error::Exists(_))));
+    }
+}
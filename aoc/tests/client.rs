@@ -0,0 +1,91 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use aoc::client::{Client, Submission, Ureq};
+use aoc::Error;
+
+/// Answer a single request with a canned response, returning the request that was received.
+fn mock_server(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        write!(
+            reader.into_inner(),
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+        .unwrap();
+        request
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_input() {
+    let (url, server) = mock_server("200 OK", "1000\n2000\n");
+    let client = Client::new(Ureq::default(), url, "secret");
+    assert_eq!(client.input(1).unwrap(), "1000\n2000\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("Cookie: session=secret\r\n"));
+}
+
+#[test]
+fn test_submit() {
+    let tests =
+        vec![
+        ("<p>That's the right answer! ...</p>", Submission::Correct),
+        ("<p>That's not the right answer. ...</p>", Submission::Incorrect),
+        ("<p>You gave an answer too recently; ...</p>", Submission::TooSoon),
+        (
+            "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+            Submission::AlreadyCompleted,
+        ),
+    ];
+    for (page, expected) in tests {
+        let (url, server) = mock_server("200 OK", page);
+        let client = Client::new(Ureq::default(), url, "secret");
+        assert_eq!(client.submit(7, 2, "24933642").unwrap(), expected);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=24933642"));
+    }
+}
+
+#[test]
+fn test_status() {
+    let (url, server) = mock_server("404 Not Found", "Not found");
+    let client = Client::new(Ureq::default(), url.clone(), "secret");
+    match client.input(26) {
+        Err(Error::Http(message)) => {
+            assert_eq!(message, format!("{}/2022/day/26/input returned 404", url))
+        }
+        other => panic!("expected an HTTP error, found {:?}", other),
+    }
+    server.join().unwrap();
+}
@@ -0,0 +1,56 @@
+use aoc_common::input::{default_path, normalise, Source};
+use aoc_common::report::Format;
+use day_01::formats::{self, InputFormat};
+use day_01::stats::Stats;
+
+/// Statistics of every elf's inventory, as a table or JSON.
+///
+/// Takes an optional input path, or `-` for stdin, `--format text` or `json`,
+/// `--buckets N` for the histogram, defaulting to 10, and `--inventory groups`, `csv`
+/// or `json` for the format of the input, detected if not given.
+fn main() {
+    if let Err(err) = run(std::env::args().skip(1)) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut format = Format::default();
+    let mut buckets = 10;
+    let mut inventory = None;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or("Expected text or json after --format")?
+                    .parse()?
+            }
+            "--buckets" => {
+                buckets = args
+                    .next()
+                    .ok_or("Expected a number after --buckets")?
+                    .parse()?
+            }
+            "--inventory" => {
+                inventory = Some(
+                    args.next()
+                        .ok_or("Expected groups, csv or json after --inventory")?
+                        .parse::<InputFormat>()?,
+                )
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument <{}>", arg).into()),
+        }
+    }
+    let input = Source::new(path.as_deref(), default_path(env!("CARGO_MANIFEST_DIR"))).read()?;
+    let elves = formats::read(&normalise(&input), inventory)?;
+    let stats = Stats::new(elves, buckets)?;
+    match format {
+        Format::Text => print!("{}", stats),
+        Format::Json => println!("{}", stats.to_json()),
+    }
+    Ok(())
+}
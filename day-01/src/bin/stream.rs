@@ -0,0 +1,77 @@
+use std::io::{BufRead, Cursor, Read};
+
+use aoc_common::input::{default_path, normalise, Source};
+use aoc_common::{params, Params, Solution};
+use day_01::formats::{self, InputFormat};
+use day_01::inventory::{top, Elves};
+use day_01::{part_1, part_2, Day01};
+
+/// Answer a part, reading the input a line at a time, for inventories too large to hold in memory.
+///
+/// Reads the same formats as the parts, detected from the first line that is not blank or given
+/// with `--inventory groups`, `csv` or `json`. Only groups are streamed; a CSV elf's rows can be
+/// anywhere in the input and a JSON array must be read whole, so those are read at once.
+///
+/// Takes an optional input path, or `-` for stdin, `--part 1` or `2`, defaulting to 2,
+/// and any number of `-P name=value` parameters, which override the day's `params.toml`
+/// like they do for the part binaries, so `top_elves` is read the same way.
+fn main() {
+    if let Err(err) = run(std::env::args().skip(1)) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let mut part = 2;
+    let mut assignments = Vec::new();
+    let mut inventory = None;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = args.next().ok_or("Expected 1 or 2 after --part")?.parse()?,
+            "-P" | "--param" => {
+                assignments.push(args.next().ok_or("Expected name=value after --param")?)
+            }
+            "--inventory" => {
+                inventory = Some(
+                    args.next()
+                        .ok_or("Expected groups, csv or json after --inventory")?
+                        .parse::<InputFormat>()?,
+                )
+            }
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument <{}>", arg).into()),
+        }
+    }
+    let mut params = Params::load(params::default_path(manifest_dir), part)?;
+    for assignment in &assignments {
+        params.set(assignment)?;
+    }
+    params.check(Day01::PARAMS)?;
+    let n = match part {
+        1 => part_1::top_elves(&params)?,
+        2 => part_2::top_elves(&params)?,
+        _ => return Err(format!("No part {}, expected 1 or 2", part).into()),
+    };
+
+    // Up to the first line that is not blank, to detect the format from, then the rest
+    let mut reader = Source::new(path.as_deref(), default_path(manifest_dir)).open()?;
+    let mut head = String::new();
+    while reader.read_line(&mut head)? > 0 && head.trim().is_empty() {}
+    let format = inventory.unwrap_or_else(|| InputFormat::detect(&normalise(&head)));
+    let mut reader = Cursor::new(head).chain(reader);
+
+    let answer = match format {
+        InputFormat::Groups => top(Elves::new(reader).totals(), n)?,
+        _ => {
+            let mut input = String::new();
+            reader.read_to_string(&mut input)?;
+            let elves = formats::read(&normalise(&input), Some(format))?;
+            top(elves.iter().map(|elf| Ok(elf.calories)), n)?
+        }
+    };
+    println!("{}", answer);
+    Ok(())
+}
@@ -0,0 +1,242 @@
+//! Inventories written other ways than groups of lines, all read into the same [`Elf`]s.
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::parse::{error_at, lines};
+use aoc_common::SolveError;
+
+use crate::inventory::{Elf, Elves};
+
+/// How an inventory is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// The puzzle's own: an item per line, each elf's items separated by a blank line.
+    Groups,
+    /// An `elf,calories` row per item, with an optional header, the elf being any label.
+    Csv,
+    /// An array of each elf's array of items.
+    Json,
+}
+
+impl InputFormat {
+    /// Format of an inventory, from its first line that is not blank.
+    pub fn detect(input: &str) -> Self {
+        let first = input.lines().map(str::trim).find(|line| !line.is_empty());
+        match first {
+            Some(line) if line.starts_with('[') => InputFormat::Json,
+            Some(line) if line.contains(',') => InputFormat::Csv,
+            _ => InputFormat::Groups,
+        }
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "groups" => Ok(InputFormat::Groups),
+            "csv" => Ok(InputFormat::Csv),
+            "json" => Ok(InputFormat::Json),
+            _ => Err(format!(
+                "Unknown inventory format <{}>, expected groups, csv or json",
+                s
+            )),
+        }
+    }
+}
+
+/// Every elf of an inventory, in the given format or the one detected.
+pub fn read(input: &str, format: Option<InputFormat>) -> Result<Vec<Elf>, SolveError> {
+    match format.unwrap_or_else(|| InputFormat::detect(input)) {
+        InputFormat::Groups => Elves::new(input.as_bytes()).collect(),
+        InputFormat::Csv => read_csv(input),
+        InputFormat::Json => read_json(input),
+    }
+}
+
+/// Elves numbered in order of their first row, whether or not their rows are together.
+fn read_csv(input: &str) -> Result<Vec<Elf>, SolveError> {
+    let mut elves = Vec::<Elf>::new();
+    let mut numbers = HashMap::new();
+    for (row, line) in lines(input).filter(|line| !line.is_blank()).enumerate() {
+        let (label, calories) = line
+            .text
+            .split_once(',')
+            .ok_or_else(|| line.error(line.text, "Expected elf,calories"))?;
+        let (label, calories) = (label.trim(), calories.trim());
+        if row == 0 && calories.eq_ignore_ascii_case("calories") {
+            continue;
+        }
+        let calories = line.parse_part::<u64>(calories)?;
+        let next = elves.len();
+        let index = *numbers.entry(label).or_insert(next);
+        if index == next {
+            elves.push(Elf {
+                number: next + 1,
+                items: 0,
+                calories: 0,
+            });
+        }
+        let elf = &mut elves[index];
+        elf.items += 1;
+        elf.calories = elf
+            .calories
+            .checked_add(calories)
+            .ok_or_else(|| line.error(line.text, "Total calories overflow u64"))?;
+    }
+    Ok(elves)
+}
+
+fn read_json(input: &str) -> Result<Vec<Elf>, SolveError> {
+    let inventory = serde_json::from_str::<Vec<Vec<u64>>>(input).map_err(|err| {
+        // serde_json's message ends with the position, which the error already has
+        let message = err.to_string();
+        let message = message
+            .rfind(" at line ")
+            .map_or(message.as_str(), |end| &message[..end]);
+        let text = input.lines().nth(err.line().max(1) - 1).unwrap_or_default();
+        SolveError::parse(err.line(), err.column(), message).with_text(text)
+    })?;
+    inventory
+        .into_iter()
+        .enumerate()
+        .map(|(index, items)| {
+            let calories = items
+                .iter()
+                .try_fold(0u64, |sum, &item| sum.checked_add(item))
+                .ok_or_else(|| {
+                    error_at(
+                        input,
+                        elf_array(input, index),
+                        "Total calories overflow u64",
+                    )
+                })?;
+            Ok(Elf {
+                number: index + 1,
+                items: items.len(),
+                calories,
+            })
+        })
+        .collect()
+}
+
+/// The input from the start of an elf's array, once the input is known to be valid.
+fn elf_array(input: &str, index: usize) -> &str {
+    let mut depth = 0;
+    let mut arrays = 0;
+    for (offset, c) in input.char_indices() {
+        match c {
+            '[' => {
+                depth += 1;
+                if depth == 2 {
+                    if arrays == index {
+                        return &input[offset..];
+                    }
+                    arrays += 1;
+                }
+            }
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    &input[input.len()..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn totals(input: &str, format: Option<InputFormat>) -> Result<Vec<u64>, SolveError> {
+        Ok(read(input, format)?
+            .iter()
+            .map(|elf| elf.calories)
+            .collect())
+    }
+
+    #[test]
+    fn test_detect() {
+        let tests = vec![
+            ("1000\n2000\n\n3000\n", InputFormat::Groups),
+            ("elf,calories\na,1000\n", InputFormat::Csv),
+            ("\n1,1000\n", InputFormat::Csv),
+            ("  [[1000, 2000], [3000]]", InputFormat::Json),
+            ("", InputFormat::Groups),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(InputFormat::detect(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_read() {
+        let tests = vec![
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n",
+            "elf,calories\n1,1000\n1,2000\n1,3000\n2,4000\n3,5000\n3,6000\n",
+            "alice, 1000\nbob, 4000\nalice, 2000\ncarol, 5000\n\nalice, 3000\ncarol, 6000\n",
+            "[[1000, 2000, 3000],\n [4000],\n [5000, 6000]]\n",
+        ];
+        for input in tests {
+            assert_eq!(
+                totals(input, None),
+                Ok(vec![6000, 4000, 11000]),
+                "{}",
+                input
+            );
+        }
+        let elves = read("[[1, 2], [], [3]]", Some(InputFormat::Json)).unwrap();
+        let items = elves.iter().map(|elf| elf.items).collect::<Vec<_>>();
+        assert_eq!(items, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            totals("a,1\nb,x2\n", None),
+            Err(SolveError::parse(2, 3, "Invalid u64: <x2>").with_text("b,x2"))
+        );
+        assert_eq!(
+            totals("a,1\nb\n", Some(InputFormat::Csv)),
+            Err(SolveError::parse(2, 1, "Expected elf,calories").with_text("b"))
+        );
+        assert_eq!(
+            totals("[[1, 2],\n [3, \"x\"]]", None),
+            Err(
+                SolveError::parse(2, 8, "invalid type: string \"x\", expected u64")
+                    .with_text(" [3, \"x\"]]")
+            )
+        );
+        assert_eq!(
+            "xml".parse::<InputFormat>(),
+            Err("Unknown inventory format <xml>, expected groups, csv or json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = u64::MAX;
+        let tests = vec![
+            (
+                format!("1\n\n{}\n1\n", max),
+                Some(InputFormat::Groups),
+                SolveError::parse(4, 1, "Total calories overflow u64").with_text("1"),
+            ),
+            (
+                format!("a,1\nb,{}\nb,1\n", max),
+                None,
+                SolveError::parse(3, 1, "Total calories overflow u64").with_text("b,1"),
+            ),
+            (
+                format!("[[1],\n  [{}, 1]]", max),
+                None,
+                SolveError::parse(2, 3, "Total calories overflow u64")
+                    .with_text(&format!("  [{}, 1]]", max)),
+            ),
+        ];
+        for (input, format, expected) in tests {
+            assert_eq!(totals(&input, format), Err(expected), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day01;
+
+impl Generate for Day01 {
+    /// `size` elves, at least three, each carrying a few items of food
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let elves = (0..size.max(3))
+            .map(|_| {
+                (0..rng.gen_range(1..=8))
+                    .map(|_| format!("{}\n", rng.gen_range(1000..=60_000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        elves.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day01::seeded(1, 20), Day01::seeded(1, 20));
+        assert_ne!(Day01::seeded(1, 20), Day01::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day01::seeded(seed, 20);
+            if let Err(err) = Day01::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Calories read line by line, so inventories larger than memory can be totalled.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use aoc_common::input::normalise_line;
+use aoc_common::parse::Line;
+use aoc_common::SolveError;
+use serde::Serialize;
+
+/// An elf's inventory, as read from its group of lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Elf {
+    /// One-based position of the elf in the input.
+    pub number: usize,
+    pub items: usize,
+    pub calories: u64,
+}
+
+/// Inventory of each elf, read one line at a time.
+pub struct Elves<R> {
+    reader: R,
+    buffer: String,
+    index: usize,
+    elves: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            index: 0,
+            elves: 0,
+            done: false,
+        }
+    }
+
+    /// Next line, without its line ending, or `None` at the end of the input.
+    fn next_line(&mut self) -> Result<Option<Line<'_>>, SolveError> {
+        self.buffer.clear();
+        let read = self.reader.read_line(&mut self.buffer).map_err(|err| {
+            SolveError::parse(self.index + 1, 1, format!("Failed to read: {}", err))
+        })?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let text = normalise_line(&self.buffer, self.index == 0);
+        self.index += 1;
+        Ok(Some(Line {
+            index: self.index - 1,
+            text,
+        }))
+    }
+
+    /// The next elf's items, skipping the blank lines before them.
+    fn next_elf(&mut self) -> Result<Option<Elf>, SolveError> {
+        let mut elf = Elf {
+            number: self.elves + 1,
+            items: 0,
+            calories: 0,
+        };
+        while let Some(line) = self.next_line()? {
+            if line.is_blank() {
+                match elf.items {
+                    0 => continue,
+                    _ => break,
+                }
+            }
+            let calories = line.parse::<u64>()?;
+            elf.items += 1;
+            elf.calories = elf
+                .calories
+                .checked_add(calories)
+                .ok_or_else(|| line.error(line.text.trim(), "Total calories overflow u64"))?;
+        }
+        if elf.items == 0 {
+            return Ok(None);
+        }
+        self.elves += 1;
+        Ok(Some(elf))
+    }
+
+    /// Total calories carried by each elf.
+    pub fn totals(self) -> impl Iterator<Item = Result<u64, SolveError>> {
+        self.map(|elf| elf.map(|elf| elf.calories))
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, SolveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let elf = self.next_elf().transpose();
+        // Stop at the end, or after the first error
+        self.done = !matches!(elf, Some(Ok(_)));
+        elf
+    }
+}
+
+/// Sum of the `n` largest totals, keeping only those `n` in memory.
+pub fn top<I>(totals: I, n: usize) -> Result<u64, SolveError>
+where
+    I: IntoIterator<Item = Result<u64, SolveError>>,
+{
+    if n == 0 {
+        return Err(SolveError::param("top_elves must be at least 1"));
+    }
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    for total in totals {
+        heap.push(Reverse(total?));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    if heap.is_empty() {
+        return Err(SolveError::no_solution("No elves in the input"));
+    }
+    heap.into_iter()
+        .try_fold(0u64, |sum, Reverse(total)| sum.checked_add(total))
+        .ok_or_else(|| SolveError::no_solution(format!("Top {} totals overflow u64", n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn totals(input: &str) -> Result<Vec<u64>, SolveError> {
+        Elves::new(input.as_bytes()).totals().collect()
+    }
+
+    #[test]
+    fn test_elves() {
+        let tests = vec![
+            ("1\n2\n\n3\n", vec![3, 3]),
+            ("\u{feff}1\r\n2\r\n\r\n\r\n3\r\n\r\n", vec![3, 3]),
+            ("\n\n1\n \n2", vec![1, 2]),
+            ("", vec![]),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(totals(input), Ok(expected));
+        }
+        let elves = Elves::new("\n1\n2\n\n\n3\n".as_bytes()).collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            elves,
+            Ok(vec![
+                Elf {
+                    number: 1,
+                    items: 2,
+                    calories: 3
+                },
+                Elf {
+                    number: 2,
+                    items: 1,
+                    calories: 3
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            totals("1\n\n-3\n"),
+            Err(SolveError::parse(3, 1, "Invalid u64: <-3>").with_text("-3"))
+        );
+        let input = format!("{}\n1\n", u64::MAX);
+        assert_eq!(
+            totals(&input),
+            Err(SolveError::parse(2, 1, "Total calories overflow u64").with_text("1"))
+        );
+        let mut elves = Elves::new("x\n\n1\n".as_bytes());
+        assert!(elves.next().unwrap().is_err());
+        assert_eq!(elves.next(), None);
+    }
+
+    #[test]
+    fn test_top() {
+        let sums = || [6000, 4000, 11000, 24000, 10000].map(Ok);
+        assert_eq!(top(sums(), 1), Ok(24000));
+        assert_eq!(top(sums(), 3), Ok(45000));
+        assert_eq!(top(sums(), 10), Ok(55000));
+        assert!(matches!(top(sums(), 0), Err(SolveError::Param(_))));
+        assert!(matches!(top([], 3), Err(SolveError::NoSolution(_))));
+        assert!(matches!(
+            top([u64::MAX, 1].map(Ok), 2),
+            Err(SolveError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn test_stream() {
+        // Far more elves than are ever held at once
+        let input = (0..100_000u64)
+            .map(|n| format!("{}\n{}\n\n", n, n))
+            .collect::<String>();
+        let elves = Elves::new(input.as_bytes());
+        assert_eq!(top(elves.totals(), 3), Ok(2 * (99_999 + 99_998 + 99_997)));
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::{Params, Solution, SolveError};
+
+use crate::inventory::top;
+
+pub mod formats;
+pub mod generate;
+pub mod inventory;
+pub mod part_1;
+pub mod part_2;
+pub mod stats;
+
+/// Calorie Counting
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<u64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    const PARAMS: &'static [&'static str] = &["top_elves"];
+
+    /// Total calories carried by each elf, in order, from an inventory in any of the [`formats`]
+    fn parse(input: &str) -> Result<Vec<u64>, SolveError> {
+        let elves = formats::read(input, None)?;
+        Ok(elves.iter().map(|elf| elf.calories).collect())
+    }
+
+    fn part_one(totals: &Vec<u64>) -> Result<u64, SolveError> {
+        compute(totals, part_1::top_elves(&Params::default())?)
+    }
+
+    fn part_one_with(totals: &Vec<u64>, params: &Params) -> Result<u64, SolveError> {
+        compute(totals, part_1::top_elves(params)?)
+    }
+
+    fn part_two(totals: &Vec<u64>) -> Result<u64, SolveError> {
+        compute(totals, part_2::top_elves(&Params::default())?)
+    }
+
+    fn part_two_with(totals: &Vec<u64>, params: &Params) -> Result<u64, SolveError> {
+        compute(totals, part_2::top_elves(params)?)
+    }
+}
+
+/// Sum of the `n` largest totals, the answer of both parts
+pub fn compute(totals: &[u64], n: usize) -> Result<u64, SolveError> {
+    top(totals.iter().copied().map(Ok), n)
+}
@@ -0,0 +1,40 @@
+use aoc_common::{Params, SolveError};
+
+const TOP_ELVES: usize = 1;
+
+/// Number of elves whose totals are added up
+pub fn top_elves(params: &Params) -> Result<usize, SolveError> {
+    params.get("top_elves", TOP_ELVES)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000",
+            24000,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day01::solve_part_one(input), Ok(expected));
+        }
+    }
+}
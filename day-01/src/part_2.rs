@@ -0,0 +1,72 @@
+use aoc_common::{Params, SolveError};
+
+const TOP_ELVES: usize = 3;
+
+/// Number of elves whose totals are added up
+pub fn top_elves(params: &Params) -> Result<usize, SolveError> {
+    params.get("top_elves", TOP_ELVES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000",
+            45000,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day01::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_formats() {
+        let tests = vec![
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n",
+            "elf,calories\n1,1000\n1,2000\n1,3000\n2,4000\n3,5000\n3,6000\n4,7000\n4,8000\n4,9000\n5,10000\n",
+            "[[1000, 2000, 3000], [4000], [5000, 6000], [7000, 8000, 9000], [10000]]\n",
+        ];
+        for input in tests {
+            assert_eq!(Day01::solve_part_two(input), Ok(45000), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Day01::solve_part_two("1000\n2000\n\n3O00"),
+            Err(SolveError::parse(4, 1, "Invalid u64: <3O00>").with_text("3O00"))
+        );
+        assert_eq!(
+            Day01::solve_part_two("a,1000\nb"),
+            Err(SolveError::parse(2, 1, "Expected elf,calories").with_text("b"))
+        );
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let mut params = Params::default();
+        params.set("top_elves=2").unwrap();
+        assert_eq!(Day01::solve_part_two_with("1\n\n2\n3\n\n4", &params), Ok(9));
+    }
+}
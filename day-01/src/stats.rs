@@ -0,0 +1,324 @@
+//! Statistics of the elves' inventories, beyond the largest totals.
+use std::fmt;
+
+use aoc_common::SolveError;
+use serde::Serialize;
+
+use crate::inventory::Elf;
+
+/// Percentiles reported, by nearest rank.
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+/// Widest bar of the histogram, in characters.
+const BAR_WIDTH: usize = 40;
+
+/// Summary of every elf's inventory.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    /// Every elf, in input order.
+    pub elves: Vec<Ranked>,
+    pub total: u128,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    /// Totals shared by more than one elf, from the highest.
+    pub ties: Vec<Tie>,
+}
+
+/// An elf, and its rank by total calories, 1 being the most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Ranked {
+    #[serde(flatten)]
+    pub elf: Elf,
+    /// Ties share a rank, and the rank after them is skipped.
+    pub rank: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Percentile {
+    pub percentile: u8,
+    pub calories: u64,
+}
+
+/// Elves whose totals fall between `from` and `to`, inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+/// Elves sharing a rank.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tie {
+    pub rank: usize,
+    pub calories: u64,
+    /// Numbers of the tied elves, in input order.
+    pub elves: Vec<usize>,
+}
+
+impl Stats {
+    /// Statistics of the elves, with totals spread over `buckets` equal ranges.
+    pub fn new(elves: Vec<Elf>, buckets: usize) -> Result<Self, SolveError> {
+        if elves.is_empty() {
+            return Err(SolveError::no_solution("No elves in the input"));
+        }
+        if buckets == 0 {
+            return Err(SolveError::param("buckets must be at least 1"));
+        }
+        let mut sorted = elves.iter().map(|elf| elf.calories).collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let count = sorted.len();
+
+        // Elves with more calories than a total, found among the sorted totals
+        let rank = |calories: u64| count - sorted.partition_point(|&c| c <= calories) + 1;
+        let elves = elves
+            .into_iter()
+            .map(|elf| Ranked {
+                elf,
+                rank: rank(elf.calories),
+            })
+            .collect::<Vec<_>>();
+
+        let total = sorted.iter().map(|&c| u128::from(c)).sum::<u128>();
+        let median = match count % 2 {
+            1 => sorted[count / 2] as f64,
+            _ => (sorted[count / 2 - 1] as f64 + sorted[count / 2] as f64) / 2.0,
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let nearest = (usize::from(percentile) * count).div_ceil(100).max(1);
+                Percentile {
+                    percentile,
+                    calories: sorted[nearest - 1],
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            ties: ties(&elves),
+            histogram: histogram(&sorted, buckets),
+            mean: total as f64 / count as f64,
+            median,
+            percentiles,
+            total,
+            elves,
+        })
+    }
+
+    /// The statistics as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("stats always serialize")
+    }
+}
+
+/// Totals spread over equal ranges from the smallest to the largest, dropping ranges past the largest.
+fn histogram(sorted: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = ((max - min) / buckets as u64).saturating_add(1);
+    let mut histogram = Vec::new();
+    let mut from = min;
+    while from <= max {
+        let to = from.saturating_add(width - 1).min(max);
+        let elves = sorted.partition_point(|&c| c <= to) - sorted.partition_point(|&c| c < from);
+        histogram.push(Bucket { from, to, elves });
+        match to.checked_add(1) {
+            Some(next) => from = next,
+            None => break,
+        }
+    }
+    histogram
+}
+
+fn ties(elves: &[Ranked]) -> Vec<Tie> {
+    let mut ranked = elves.to_vec();
+    ranked.sort_by_key(|ranked| (ranked.rank, ranked.elf.number));
+    ranked
+        .chunk_by(|a, b| a.rank == b.rank)
+        .filter(|group| group.len() > 1)
+        .map(|group| Tie {
+            rank: group[0].rank,
+            calories: group[0].elf.calories,
+            elves: group.iter().map(|ranked| ranked.elf.number).collect(),
+        })
+        .collect()
+}
+
+/// A table of every elf, then the summary, histogram and ties.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>6} {:>6} {:>10} {:>6}",
+            "Elf", "Items", "Calories", "Rank"
+        )?;
+        for Ranked { elf, rank } in &self.elves {
+            writeln!(
+                f,
+                "{:>6} {:>6} {:>10} {:>6}",
+                elf.number, elf.items, elf.calories, rank
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{:<8} {}", "Elves", self.elves.len())?;
+        writeln!(f, "{:<8} {}", "Total", self.total)?;
+        writeln!(f, "{:<8} {:.1}", "Mean", self.mean)?;
+        writeln!(f, "{:<8} {:.1}", "Median", self.median)?;
+        for Percentile {
+            percentile,
+            calories,
+        } in &self.percentiles
+        {
+            writeln!(f, "{:<8} {}", format!("P{}", percentile), calories)?;
+        }
+
+        writeln!(f)?;
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        for Bucket { from, to, elves } in &self.histogram {
+            let bar = (elves * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>digits$} - {:>digits$} | {:<BAR_WIDTH$} {}",
+                from,
+                to,
+                "#".repeat(bar),
+                elves,
+                digits = digits,
+            )?;
+        }
+
+        if !self.ties.is_empty() {
+            writeln!(f)?;
+        }
+        for Tie {
+            rank,
+            calories,
+            elves,
+        } in &self.ties
+        {
+            let elves = elves.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+            writeln!(
+                f,
+                "Rank {}: {} calories, elves {}",
+                rank,
+                calories,
+                elves.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::Elves;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn stats(input: &str, buckets: usize) -> Result<Stats, SolveError> {
+        let elves = Elves::new(input.as_bytes()).collect::<Result<Vec<_>, _>>()?;
+        Stats::new(elves, buckets)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = stats(
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n",
+            4,
+        )
+        .unwrap();
+        let ranks = stats.elves.iter().map(|r| r.rank).collect::<Vec<_>>();
+        assert_eq!(ranks, vec![4, 5, 2, 1, 3]);
+        assert_eq!(stats.elves[3].elf.items, 3);
+        assert_eq!(stats.total, 55000);
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        let percentiles = stats
+            .percentiles
+            .iter()
+            .map(|p| p.calories)
+            .collect::<Vec<_>>();
+        assert_eq!(percentiles, vec![4000, 6000, 10000, 11000, 24000, 24000]);
+        let buckets = stats
+            .histogram
+            .iter()
+            .map(|b| (b.from, b.to, b.elves))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            vec![
+                (4000, 9000, 2),
+                (9001, 14001, 2),
+                (14002, 19002, 0),
+                (19003, 24000, 1)
+            ]
+        );
+        assert!(stats.ties.is_empty());
+    }
+
+    #[test]
+    fn test_ties() {
+        let stats = stats("5\n\n3\n\n5\n\n1\n2\n\n7\n", 1).unwrap();
+        let ranks = stats.elves.iter().map(|r| r.rank).collect::<Vec<_>>();
+        assert_eq!(ranks, vec![2, 4, 2, 4, 1]);
+        assert_eq!(stats.median, 5.0);
+        assert_eq!(
+            stats.ties,
+            vec![
+                Tie {
+                    rank: 2,
+                    calories: 5,
+                    elves: vec![1, 3]
+                },
+                Tie {
+                    rank: 4,
+                    calories: 3,
+                    elves: vec![2, 4]
+                },
+            ]
+        );
+        assert_eq!(
+            stats.histogram,
+            vec![Bucket {
+                from: 3,
+                to: 7,
+                elves: 5
+            }]
+        );
+        assert!(stats
+            .to_string()
+            .ends_with("Rank 2: 5 calories, elves 1, 3\nRank 4: 3 calories, elves 2, 4\n"));
+    }
+
+    #[test]
+    fn test_json() {
+        let json = stats("5\n\n3\n\n5\n", 2).unwrap().to_json();
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        assert_eq!(
+            value["elves"][0],
+            serde_json::json!({"number": 1, "items": 1, "calories": 5, "rank": 1})
+        );
+        assert_eq!(value["median"], 5.0);
+        assert_eq!(value["ties"][0]["elves"], serde_json::json!([1, 3]));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(stats("", 10), Err(SolveError::NoSolution(_))));
+        assert!(matches!(stats("1\n", 0), Err(SolveError::Param(_))));
+        let stats = stats("1\n\n1\n", 10).unwrap();
+        assert_eq!(
+            stats.histogram,
+            vec![Bucket {
+                from: 1,
+                to: 1,
+                elves: 2
+            }]
+        );
+    }
+}
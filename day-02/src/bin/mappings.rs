@@ -0,0 +1,24 @@
+use aoc_common::input::{default_path, normalise, Source};
+use aoc_common::Solution;
+use day_02::game::Game;
+use day_02::mapping::Evaluation;
+use day_02::Day02;
+
+/// Score of the strategy guide for every reading of its second column, each letter meaning
+/// any move or outcome, from the best.
+///
+/// Takes an optional input path, or `-` for stdin.
+fn main() {
+    if let Err(err) = run(std::env::args().nth(1)) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(path: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let input = Source::new(path.as_deref(), default_path(env!("CARGO_MANIFEST_DIR"))).read()?;
+    let game = Game::rock_paper_scissors();
+    let rounds = Day02::parse(&normalise(&input))?;
+    print!("{}", Evaluation::new(&game, &rounds)?.table(&game));
+    Ok(())
+}
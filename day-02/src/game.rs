@@ -0,0 +1,305 @@
+//! Hand games where which move beats which is data, like Rock Paper Scissors Lizard Spock.
+use std::fmt;
+
+use crate::Outcome;
+
+/// A move of a [`Game`], by its position in the game's list of shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move(pub usize);
+
+/// A move's name, and the points for playing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub score: i32,
+}
+
+impl Shape {
+    pub fn new(name: impl Into<String>, score: i32) -> Self {
+        Self {
+            name: name.into(),
+            score,
+        }
+    }
+}
+
+/// Points for the outcome of a round, added to the points of the shape played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scores {
+    pub win: i32,
+    pub draw: i32,
+    pub lose: i32,
+}
+
+impl Scores {
+    pub fn of(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Win => self.win,
+            Outcome::Draw => self.draw,
+            Outcome::Lose => self.lose,
+        }
+    }
+}
+
+/// A fair game: any two different moves have a winner, and every move beats as many moves as it loses to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    /// Whether the move in the row beats the move in the column.
+    beats: Vec<Vec<bool>>,
+    scores: Scores,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// A game needs at least one move.
+    Empty,
+    /// A rule names a move past the end of the shapes.
+    UnknownMove(usize),
+    /// A move is said to beat itself.
+    BeatsItself(String),
+    /// Two moves are each said to beat the other.
+    BeatEachOther(String, String),
+    /// Two moves have no rule between them.
+    Undecided(String, String),
+    /// A move beats more or fewer moves than it loses to.
+    Unfair { name: String, beats: usize },
+    /// A cyclic game has an even number of moves, so some pairs would beat each other.
+    Even(usize),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::Empty => write!(f, "A game needs at least one move"),
+            GameError::UnknownMove(index) => write!(f, "No move {}", index),
+            GameError::BeatsItself(name) => write!(f, "{} beats itself", name),
+            GameError::BeatEachOther(a, b) => write!(f, "{} and {} beat each other", a, b),
+            GameError::Undecided(a, b) => write!(f, "Neither {} nor {} wins", a, b),
+            GameError::Unfair { name, beats } => {
+                write!(
+                    f,
+                    "{} beats {} moves, but the game is not balanced",
+                    name, beats
+                )
+            }
+            GameError::Even(n) => {
+                write!(f, "A cyclic game needs an odd number of moves, not {}", n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl Game {
+    /// Game from its shapes and every pair `(winner, loser)` of indices into them.
+    pub fn new(
+        shapes: Vec<Shape>,
+        rules: &[(usize, usize)],
+        scores: Scores,
+    ) -> Result<Self, GameError> {
+        let n = shapes.len();
+        if n == 0 {
+            return Err(GameError::Empty);
+        }
+        let name = |index: usize| shapes[index].name.clone();
+        let mut beats = vec![vec![false; n]; n];
+        for &(winner, loser) in rules {
+            if let Some(&unknown) = [winner, loser].iter().find(|&&index| index >= n) {
+                return Err(GameError::UnknownMove(unknown));
+            }
+            if winner == loser {
+                return Err(GameError::BeatsItself(name(winner)));
+            }
+            beats[winner][loser] = true;
+        }
+        let pairs = (0..n).flat_map(|a| (a + 1..n).map(move |b| (a, b)));
+        for (a, b) in pairs {
+            match (beats[a][b], beats[b][a]) {
+                (true, true) => return Err(GameError::BeatEachOther(name(a), name(b))),
+                (false, false) => return Err(GameError::Undecided(name(a), name(b))),
+                _ => {}
+            }
+        }
+        // Only possible with an odd number of moves
+        for (a, row) in beats.iter().enumerate() {
+            let count = row.iter().filter(|&&beaten| beaten).count();
+            if count * 2 != n - 1 {
+                return Err(GameError::Unfair {
+                    name: name(a),
+                    beats: count,
+                });
+            }
+        }
+        Ok(Self {
+            shapes,
+            beats,
+            scores,
+        })
+    }
+
+    /// Game where each move beats the moves one, three, five... places before it, wrapping around.
+    ///
+    /// With Rock, Paper, Scissors, Spock, Lizard in that order, this is Rock Paper Scissors Lizard Spock.
+    pub fn cyclic(shapes: Vec<Shape>, scores: Scores) -> Result<Self, GameError> {
+        let n = shapes.len();
+        if n > 0 && n.is_multiple_of(2) {
+            return Err(GameError::Even(n));
+        }
+        let rules = (0..n)
+            .flat_map(|winner| {
+                (1..n)
+                    .step_by(2)
+                    .map(move |k| (winner, (winner + n - k) % n))
+            })
+            .collect::<Vec<_>>();
+        Self::new(shapes, &rules, scores)
+    }
+
+    /// The puzzle's game, scored as the Elves do.
+    pub fn rock_paper_scissors() -> Self {
+        let shapes = vec![
+            Shape::new("Rock", 1),
+            Shape::new("Paper", 2),
+            Shape::new("Scissors", 3),
+        ];
+        Self::cyclic(shapes, ELF_SCORES).expect("Rock Paper Scissors is fair")
+    }
+
+    /// Rock Paper Scissors with two more moves, scored like the puzzle's game.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let shapes = vec![
+            Shape::new("Rock", 1),
+            Shape::new("Paper", 2),
+            Shape::new("Scissors", 3),
+            Shape::new("Spock", 4),
+            Shape::new("Lizard", 5),
+        ];
+        Self::cyclic(shapes, ELF_SCORES).expect("Rock Paper Scissors Lizard Spock is fair")
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.shapes.len()).map(Move)
+    }
+
+    pub fn shape(&self, m: Move) -> &Shape {
+        &self.shapes[m.0]
+    }
+
+    /// How a round ends for the player of `me`.
+    pub fn battle(&self, me: Move, other: Move) -> Outcome {
+        if self.beats[me.0][other.0] {
+            Outcome::Win
+        } else if self.beats[other.0][me.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Move ending a round against `other` as wanted, the highest scoring if several do.
+    pub fn choose(&self, other: Move, outcome: Outcome) -> Option<Move> {
+        self.moves()
+            .filter(|&me| self.battle(me, other) == outcome)
+            .max_by_key(|&me| (self.shape(me).score, std::cmp::Reverse(me.0)))
+    }
+
+    /// Score of a round, played against `other`.
+    pub fn play(&self, me: Move, other: Move) -> i32 {
+        self.shape(me).score + self.scores.of(self.battle(me, other))
+    }
+}
+
+/// Six points for a win, three for a draw and none for a loss.
+pub const ELF_SCORES: Scores = Scores {
+    win: 6,
+    draw: 3,
+    lose: 0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+        let [rock, paper, scissors] = [Move(0), Move(1), Move(2)];
+        assert_eq!(game.battle(paper, rock), Outcome::Win);
+        assert_eq!(game.battle(rock, paper), Outcome::Lose);
+        assert_eq!(game.battle(scissors, paper), Outcome::Win);
+        assert_eq!(game.battle(rock, scissors), Outcome::Win);
+        assert_eq!(game.battle(scissors, scissors), Outcome::Draw);
+        assert_eq!(game.choose(rock, Outcome::Win), Some(paper));
+        assert_eq!(game.choose(rock, Outcome::Lose), Some(scissors));
+        assert_eq!(game.choose(paper, Outcome::Draw), Some(paper));
+        assert_eq!(game.play(paper, rock), 8);
+        assert_eq!(game.play(rock, paper), 1);
+        assert_eq!(game.play(scissors, scissors), 6);
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let find = |name: &str| game.moves().find(|&m| game.shape(m).name == name).unwrap();
+        let rules = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+        for (winner, loser) in rules {
+            assert_eq!(game.battle(find(winner), find(loser)), Outcome::Win);
+            assert_eq!(game.battle(find(loser), find(winner)), Outcome::Lose);
+        }
+        // Lizard and Spock both beat Paper, and Lizard scores more
+        assert_eq!(
+            game.choose(find("Paper"), Outcome::Win),
+            Some(find("Lizard"))
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let shapes = |n: usize| (0..n).map(|i| Shape::new(i.to_string(), 1)).collect();
+        assert_eq!(Game::cyclic(vec![], ELF_SCORES), Err(GameError::Empty));
+        assert_eq!(Game::cyclic(shapes(4), ELF_SCORES), Err(GameError::Even(4)));
+        let tests = vec![
+            (vec![(0, 3)], GameError::UnknownMove(3)),
+            (vec![(1, 1)], GameError::BeatsItself("1".to_string())),
+            (
+                vec![(0, 1), (1, 0)],
+                GameError::BeatEachOther("0".to_string(), "1".to_string()),
+            ),
+            (
+                vec![(0, 1)],
+                GameError::Undecided("0".to_string(), "2".to_string()),
+            ),
+            (
+                vec![(0, 1), (0, 2), (1, 2)],
+                GameError::Unfair {
+                    name: "0".to_string(),
+                    beats: 2,
+                },
+            ),
+        ];
+        for (rules, expected) in tests {
+            assert_eq!(Game::new(shapes(3), &rules, ELF_SCORES), Err(expected));
+        }
+        let single = Game::cyclic(shapes(1), ELF_SCORES).unwrap();
+        assert_eq!(single.choose(Move(0), Outcome::Win), None);
+    }
+}
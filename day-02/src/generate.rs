@@ -0,0 +1,37 @@
+use aoc_common::generate::{Generate, Random, SliceRandom};
+
+use crate::Day02;
+
+impl Generate for Day02 {
+    /// `size` rounds of the strategy guide
+    fn generate(rng: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let opponent = ["A", "B", "C"].choose(rng).unwrap();
+                let strategy = ["X", "Y", "Z"].choose(rng).unwrap();
+                format!("{} {}\n", opponent, strategy)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day02::seeded(1, 20), Day02::seeded(1, 20));
+        assert_ne!(Day02::seeded(1, 20), Day02::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day02::seeded(seed, 20);
+            if let Err(err) = Day02::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use aoc_common::parse::lines;
+use aoc_common::{Solution, SolveError};
+use game::Move;
+
+pub mod game;
+pub mod generate;
+pub mod mapping;
+pub mod part_1;
+pub mod part_2;
+
+/// Rock Paper Scissors
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(Rps, Strategy)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Each round of the strategy guide
+    fn parse(input: &str) -> Result<Vec<(Rps, Strategy)>, SolveError> {
+        let mut rounds = Vec::new();
+        for line in lines(input) {
+            let v = line.text.split_whitespace().collect::<Vec<&str>>();
+            if v.len() != 2 {
+                return Err(line.error(line.text, "Expected two columns"));
+            }
+            let opp = line.parse_part::<Rps>(v[0])?;
+            let strategy = line.parse_part::<Strategy>(v[1])?;
+            rounds.push((opp, strategy));
+        }
+        Ok(rounds)
+    }
+
+    fn part_one(rounds: &Vec<(Rps, Strategy)>) -> Result<i32, SolveError> {
+        part_1::compute(rounds)
+    }
+
+    fn part_two(rounds: &Vec<(Rps, Strategy)>) -> Result<i32, SolveError> {
+        part_2::compute(rounds)
+    }
+}
+
+/// The opponent's column of the strategy guide.
+#[derive(Debug, PartialEq)]
+pub enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for Rps {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Rps::Rock),
+            "B" => Ok(Rps::Paper),
+            "C" => Ok(Rps::Scissors),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<&Rps> for Move {
+    /// The shape in the puzzle's game
+    fn from(shape: &Rps) -> Self {
+        match shape {
+            Rps::Rock => Move(0),
+            Rps::Paper => Move(1),
+            Rps::Scissors => Move(2),
+        }
+    }
+}
+
+/// The second column of the strategy guide, which each part reads differently.
+#[derive(Debug, PartialEq)]
+pub enum Strategy {
+    X,
+    Y,
+    Z,
+}
+
+impl Strategy {
+    /// Position of the letter, X being 0
+    pub fn index(&self) -> usize {
+        match self {
+            Strategy::X => 0,
+            Strategy::Y => 1,
+            Strategy::Z => 2,
+        }
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Strategy::X),
+            "Y" => Ok(Strategy::Y),
+            "Z" => Ok(Strategy::Z),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
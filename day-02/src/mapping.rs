@@ -0,0 +1,228 @@
+//! Every way the second column of the strategy guide could be read, and what each would score.
+use aoc_common::SolveError;
+
+use crate::game::{Game, Move};
+use crate::{Outcome, Rps, Strategy};
+
+/// What a letter of the second column asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meaning {
+    /// The shape to play.
+    Move(Move),
+    /// How the round needs to end.
+    Outcome(Outcome),
+}
+
+/// A reading of the second column: what X, Y and Z each mean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping(pub [Meaning; 3]);
+
+impl Mapping {
+    /// Part 1's reading, X, Y and Z being Rock, Paper and Scissors.
+    pub const MOVES: Mapping = Mapping([
+        Meaning::Move(Move(0)),
+        Meaning::Move(Move(1)),
+        Meaning::Move(Move(2)),
+    ]);
+
+    /// Part 2's reading, X, Y and Z being a loss, a draw and a win.
+    pub const OUTCOMES: Mapping = Mapping([
+        Meaning::Outcome(Outcome::Lose),
+        Meaning::Outcome(Outcome::Draw),
+        Meaning::Outcome(Outcome::Win),
+    ]);
+
+    /// Every reading, each letter meaning any move or outcome, even one another letter means too.
+    pub fn all(game: &Game) -> Vec<Mapping> {
+        let meanings = game
+            .moves()
+            .map(Meaning::Move)
+            .chain([Outcome::Lose, Outcome::Draw, Outcome::Win].map(Meaning::Outcome))
+            .collect::<Vec<_>>();
+        let mut mappings = Vec::new();
+        for &x in &meanings {
+            for &y in &meanings {
+                for &z in &meanings {
+                    mappings.push(Mapping([x, y, z]));
+                }
+            }
+        }
+        mappings
+    }
+
+    /// Total score of the rounds, read this way.
+    pub fn score(&self, game: &Game, rounds: &[(Rps, Strategy)]) -> Result<i32, SolveError> {
+        let mut score = 0;
+        for (opp, strategy) in rounds {
+            let opp = Move::from(opp);
+            let me = match self.0[strategy.index()] {
+                Meaning::Move(me) => me,
+                Meaning::Outcome(outcome) => game
+                    .choose(opp, outcome)
+                    .ok_or_else(|| SolveError::no_solution("No move ends the round as needed"))?,
+            };
+            score += game.play(me, opp);
+        }
+        Ok(score)
+    }
+
+    /// The reading, naming the game's shapes, like `X=Rock Y=Paper Z=Scissors`.
+    pub fn describe(&self, game: &Game) -> String {
+        ["X", "Y", "Z"]
+            .iter()
+            .zip(self.0)
+            .map(|(letter, meaning)| match meaning {
+                Meaning::Move(m) => format!("{}={}", letter, game.shape(m).name),
+                Meaning::Outcome(outcome) => format!("{}={:?}", letter, outcome),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The score of every reading of a strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    /// Each reading and its score, from the highest score.
+    pub scores: Vec<(Mapping, i32)>,
+}
+
+impl Evaluation {
+    pub fn new(game: &Game, rounds: &[(Rps, Strategy)]) -> Result<Self, SolveError> {
+        let mut scores = Mapping::all(game)
+            .into_iter()
+            .map(|mapping| Ok((mapping, mapping.score(game, rounds)?)))
+            .collect::<Result<Vec<_>, SolveError>>()?;
+        // Stable, so equal scores keep the order of `Mapping::all`
+        scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        Ok(Self { scores })
+    }
+
+    /// Readings sharing the highest score.
+    pub fn best(&self) -> Vec<Mapping> {
+        self.with_score(self.scores.first().map(|&(_, score)| score))
+    }
+
+    /// Readings sharing the lowest score.
+    pub fn worst(&self) -> Vec<Mapping> {
+        self.with_score(self.scores.last().map(|&(_, score)| score))
+    }
+
+    fn with_score(&self, wanted: Option<i32>) -> Vec<Mapping> {
+        self.scores
+            .iter()
+            .filter(|&&(_, score)| Some(score) == wanted)
+            .map(|&(mapping, _)| mapping)
+            .collect()
+    }
+
+    /// A row per reading, from the best, marking the best and worst ones and the parts' own.
+    pub fn table(&self, game: &Game) -> String {
+        let (best, worst) = (self.best(), self.worst());
+        let mut table = String::new();
+        for (mapping, score) in &self.scores {
+            let mut notes = Vec::new();
+            if best.contains(mapping) {
+                notes.push("best");
+            }
+            if worst.contains(mapping) {
+                notes.push("worst");
+            }
+            if *mapping == Mapping::MOVES {
+                notes.push("part 1");
+            }
+            if *mapping == Mapping::OUTCOMES {
+                notes.push("part 2");
+            }
+            let line = format!(
+                "{:<36} {:>8}  {}",
+                mapping.describe(game),
+                score,
+                notes.join(", ")
+            );
+            table.push_str(line.trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn test_all() {
+        let game = Game::rock_paper_scissors();
+        let mappings = Mapping::all(&game);
+        // Three moves and three outcomes for each of X, Y and Z
+        assert_eq!(mappings.len(), 6 * 6 * 6);
+        assert!(mappings.contains(&Mapping::MOVES));
+        assert!(mappings.contains(&Mapping::OUTCOMES));
+        let rock = Meaning::Move(Move(0));
+        let mixed = Mapping([rock, Meaning::Outcome(Outcome::Draw), rock]);
+        assert!(mappings.contains(&mixed));
+        let lizard_spock = Game::rock_paper_scissors_lizard_spock();
+        assert_eq!(Mapping::all(&lizard_spock).len(), 8 * 8 * 8);
+    }
+
+    #[test]
+    fn test_evaluation() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day02::parse(EXAMPLE).unwrap();
+        let evaluation = Evaluation::new(&game, &rounds).unwrap();
+        let score = |wanted: Mapping| {
+            evaluation
+                .scores
+                .iter()
+                .find(|&&(mapping, _)| mapping == wanted)
+                .map(|&(_, score)| score)
+        };
+        let [rock, paper, scissors] = [0, 1, 2].map(|n| Meaning::Move(Move(n)));
+        let [lose, draw, win] = [Outcome::Lose, Outcome::Draw, Outcome::Win].map(Meaning::Outcome);
+        assert_eq!(score(Mapping::MOVES), Some(15));
+        assert_eq!(score(Mapping::OUTCOMES), Some(12));
+        assert_eq!(score(Mapping([rock, rock, rock])), Some(1 + 4 + 7));
+        assert_eq!(score(Mapping([rock, draw, win])), Some(1 + 4 + 7));
+
+        // Winning every round, by naming the winning move or the win itself
+        let best = evaluation.best();
+        assert_eq!(best.len(), 8);
+        assert_eq!(best[0], Mapping([scissors, paper, rock]));
+        assert!(best.contains(&Mapping([win, win, win])));
+        assert!(best.contains(&Mapping([scissors, win, rock])));
+        assert!(best.iter().all(|&mapping| score(mapping) == Some(24)));
+
+        let worst = evaluation.worst();
+        assert_eq!(worst.len(), 8);
+        assert_eq!(worst[0], Mapping([rock, scissors, paper]));
+        assert!(worst.contains(&Mapping([lose, lose, lose])));
+        assert!(worst.iter().all(|&mapping| score(mapping) == Some(6)));
+    }
+
+    #[test]
+    fn test_table() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day02::parse(EXAMPLE).unwrap();
+        let table = Evaluation::new(&game, &rounds).unwrap().table(&game);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 216);
+        assert_eq!(
+            lines[0],
+            "X=Scissors Y=Paper Z=Rock                  24  best"
+        );
+        assert!(lines.contains(&"X=Win Y=Win Z=Win                          24  best"));
+        assert!(lines.contains(&"X=Rock Y=Paper Z=Scissors                  15  part 1"));
+        assert!(lines.contains(&"X=Lose Y=Draw Z=Win                        12  part 2"));
+        assert_eq!(
+            lines[215],
+            "X=Lose Y=Lose Z=Lose                        6  worst"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::SolveError;
+
+use crate::game::Game;
+use crate::mapping::Mapping;
+use crate::{Rps, Strategy};
+
+/// Answer for the parsed input
+pub fn compute(rounds: &[(Rps, Strategy)]) -> Result<i32, SolveError> {
+    // The second column is the shape to play
+    Mapping::MOVES.score(&Game::rock_paper_scissors(), rounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_common::{Solution, SolveError};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "A Y
+B X
+C Z",
+            8 + 1 + 6,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day02::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Day02::solve_part_one("A Y\nB W"),
+            Err(SolveError::parse(2, 3, "Invalid Strategy: <W>").with_text("B W"))
+        );
+    }
+}
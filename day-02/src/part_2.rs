@@ -0,0 +1,32 @@
+use aoc_common::SolveError;
+
+use crate::game::Game;
+use crate::mapping::Mapping;
+use crate::{Rps, Strategy};
+
+/// Answer for the parsed input
+pub fn compute(rounds: &[(Rps, Strategy)]) -> Result<i32, SolveError> {
+    // The second column is how the round needs to end
+    Mapping::OUTCOMES.score(&Game::rock_paper_scissors(), rounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "A Y
+B X
+C Z",
+            12,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day02::solve_part_two(input), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::generate::{Generate, Random, Rng, SliceRandom};
+
+use crate::Day03;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generate for Day03 {
+    /// `size` groups of three rucksacks, each with one item in both compartments and one badge per group
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let mut items = ITEMS.chars().collect::<Vec<_>>();
+            items.shuffle(rng);
+            let badge = items.pop().unwrap();
+            // Each elf has their own items, so only the badge is in all three rucksacks
+            for own in items.chunks(items.len() / 3).take(3) {
+                input.push_str(&rucksack(rng, own, badge));
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+/// Rucksack of the elf's own items and the badge, with only the first of its own in both compartments.
+fn rucksack(rng: &mut Random, own: &[char], badge: char) -> String {
+    let (shared, rest) = own.split_first().unwrap();
+    let (left, right) = rest.split_at(rest.len() / 2);
+    let length = rng.gen_range(4..=16);
+    let mut compartments = [left, right].map(|pool| {
+        let mut compartment = vec![*shared];
+        compartment.extend((1..length).map(|_| *pool.choose(rng).unwrap()));
+        compartment
+    });
+    let badged = &mut compartments[rng.gen_range(0..2)];
+    badged[rng.gen_range(1..length)] = badge;
+    compartments
+        .into_iter()
+        .flat_map(|mut compartment| {
+            compartment.shuffle(rng);
+            compartment
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day03::seeded(1, 20), Day03::seeded(1, 20));
+        assert_ne!(Day03::seeded(1, 20), Day03::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day03::seeded(seed, 20);
+            if let Err(err) = Day03::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
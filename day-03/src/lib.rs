@@ -0,0 +1,111 @@
+use aoc_common::parse::{lines, Line};
+use aoc_common::{Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Rucksack Reorganization
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Rucksack<'a>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Every rucksack in the input
+    fn parse(input: &str) -> Result<Vec<Rucksack<'_>>, SolveError> {
+        lines(input)
+            .map(|line| {
+                Ok(Rucksack {
+                    items: priorities(&line)?,
+                    line,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(rucksacks: &Vec<Rucksack<'_>>) -> Result<i32, SolveError> {
+        part_1::compute(rucksacks)
+    }
+
+    fn part_two(rucksacks: &Vec<Rucksack<'_>>) -> Result<i32, SolveError> {
+        part_2::compute(rucksacks)
+    }
+}
+
+/// Priorities of the items in a rucksack, and the line listing them
+#[derive(Debug)]
+pub struct Rucksack<'a> {
+    line: Line<'a>,
+    items: Vec<i32>,
+}
+
+/// Priority of each item in the rucksack
+fn priorities(line: &Line) -> Result<Vec<i32>, SolveError> {
+    line.text
+        .char_indices()
+        .map(|(column, c)| {
+            letter_to_number(c)
+                .ok_or_else(|| line.error(&line.text[column..], format!("Invalid item <{}>", c)))
+        })
+        .collect()
+}
+
+fn letter_to_number(letter: char) -> Option<i32> {
+    match letter {
+        'a' => Some(1),
+        'b' => Some(2),
+        'c' => Some(3),
+        'd' => Some(4),
+        'e' => Some(5),
+        'f' => Some(6),
+        'g' => Some(7),
+        'h' => Some(8),
+        'i' => Some(9),
+        'j' => Some(10),
+        'k' => Some(11),
+        'l' => Some(12),
+        'm' => Some(13),
+        'n' => Some(14),
+        'o' => Some(15),
+        'p' => Some(16),
+        'q' => Some(17),
+        'r' => Some(18),
+        's' => Some(19),
+        't' => Some(20),
+        'u' => Some(21),
+        'v' => Some(22),
+        'w' => Some(23),
+        'x' => Some(24),
+        'y' => Some(25),
+        'z' => Some(26),
+        'A' => Some(27),
+        'B' => Some(28),
+        'C' => Some(29),
+        'D' => Some(30),
+        'E' => Some(31),
+        'F' => Some(32),
+        'G' => Some(33),
+        'H' => Some(34),
+        'I' => Some(35),
+        'J' => Some(36),
+        'K' => Some(37),
+        'L' => Some(38),
+        'M' => Some(39),
+        'N' => Some(40),
+        'O' => Some(41),
+        'P' => Some(42),
+        'Q' => Some(43),
+        'R' => Some(44),
+        'S' => Some(45),
+        'T' => Some(46),
+        'U' => Some(47),
+        'V' => Some(48),
+        'W' => Some(49),
+        'X' => Some(50),
+        'Y' => Some(51),
+        'Z' => Some(52),
+        _ => None,
+    }
+}
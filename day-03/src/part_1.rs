@@ -0,0 +1,50 @@
+use aoc_common::SolveError;
+
+use crate::Rucksack;
+
+/// Answer for the parsed input
+pub fn compute(rucksacks: &[Rucksack]) -> Result<i32, SolveError> {
+    let mut sum = 0;
+    for rucksack in rucksacks {
+        let items = &rucksack.items;
+        let first_compartment = &items[0..items.len() / 2];
+        let second_compartment = &items[items.len() / 2..];
+
+        let mut letter = None;
+        for c in first_compartment {
+            if second_compartment.contains(c) {
+                letter = Some(*c);
+                break;
+            }
+        }
+        match letter {
+            Some(priority) => sum += priority,
+            None => return Err(rucksack.line.error(rucksack.line.text, "No letter found")),
+        }
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+            157,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day03::solve_part_one(input), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::SolveError;
+
+use crate::Rucksack;
+
+/// Answer for the parsed input
+pub fn compute(rucksacks: &[Rucksack]) -> Result<i32, SolveError> {
+    if let Some(last) = rucksacks
+        .last()
+        .filter(|_| !rucksacks.len().is_multiple_of(3))
+    {
+        return Err(SolveError::parse(
+            last.line.number(),
+            last.line.text.chars().count() + 1,
+            "Unexpected end of input, expected a group of three rucksacks",
+        ));
+    }
+
+    let mut sum = 0;
+    for group in rucksacks.chunks(3) {
+        let mut shared = None;
+        for c in group[0].items.iter() {
+            if group[1].items.contains(c) && group[2].items.contains(c) {
+                shared = Some(*c);
+                break;
+            }
+        }
+        match shared {
+            Some(priority) => sum += priority,
+            None => {
+                return Err(group[0]
+                    .line
+                    .error(group[0].line.text, "No shared letter found"))
+            }
+        }
+    }
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+            70,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day03::solve_part_two(input), Ok(expected));
+        }
+    }
+}
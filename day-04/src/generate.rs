@@ -0,0 +1,40 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day04;
+
+impl Generate for Day04 {
+    /// `size` pairs of section assignments
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            (start, rng.gen_range(start..=99))
+        };
+        (0..size.max(1))
+            .map(|_| {
+                let ((a, b), (c, d)) = (range(), range());
+                format!("{}-{},{}-{}\n", a, b, c, d)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day04::seeded(1, 20), Day04::seeded(1, 20));
+        assert_ne!(Day04::seeded(1, 20), Day04::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day04::seeded(seed, 20);
+            if let Err(err) = Day04::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::character::complete::newline;
+use nom::combinator::map_res;
+use nom::combinator::opt;
+use nom::multi::many1;
+use nom::sequence::separated_pair;
+use nom::sequence::terminated;
+use nom::IResult;
+
+use aoc_common::parse::error_at;
+use aoc_common::{Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Camp Cleanup
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<(Assignment, Assignment)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Every pair of assignments
+    fn parse(input: &str) -> Result<Vec<(Assignment, Assignment)>, SolveError> {
+        parse_pairs(input)
+    }
+
+    fn part_one(pairs: &Vec<(Assignment, Assignment)>) -> Result<i32, SolveError> {
+        part_1::compute(pairs)
+    }
+
+    fn part_two(pairs: &Vec<(Assignment, Assignment)>) -> Result<i32, SolveError> {
+        part_2::compute(pairs)
+    }
+}
+
+pub type Assignment = std::ops::RangeInclusive<u32>;
+
+fn parse_range(input: &str) -> IResult<&str, Assignment> {
+    map_res(
+        separated_pair(
+            map_res(digit1, str::parse::<u32>),
+            tag("-"),
+            map_res(digit1, str::parse::<u32>),
+        ),
+        |(l, h)| Ok::<Assignment, String>(l..=h),
+    )(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (Assignment, Assignment)> {
+    separated_pair(parse_range, tag(","), parse_range)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Assignment, Assignment)> {
+    terminated(parse_pair, opt(newline))(input)
+}
+
+/// Get pairs from input file, failing on anything left unparsed.
+fn parse_pairs(input: &str) -> Result<Vec<(Assignment, Assignment)>, SolveError> {
+    let rest = match parse_file(input) {
+        Ok((rest, pairs)) if rest.trim().is_empty() => return Ok(pairs),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+    Err(error_at(
+        input,
+        rest,
+        "Expected a pair of ranges, like <2-4,6-8>",
+    ))
+}
+
+/// Get pairs from input file.
+fn parse_file(input: &str) -> IResult<&str, Vec<(Assignment, Assignment)>> {
+    let (input, pairs) = many1(parse_line)(input)?;
+
+    Ok((input, pairs))
+}
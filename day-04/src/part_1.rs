@@ -0,0 +1,52 @@
+use aoc_common::SolveError;
+
+use crate::Assignment;
+
+/// Answer for the parsed input
+pub fn compute(pairs: &[(Assignment, Assignment)]) -> Result<i32, SolveError> {
+    let mut tally = 0;
+    for (elf_1, elf_2) in pairs.iter() {
+        if (elf_1.contains(elf_2.start()) && elf_1.contains(elf_2.end()))
+            || (elf_2.contains(elf_1.start()) && elf_2.contains(elf_1.end()))
+        {
+            tally += 1;
+        }
+    }
+    Ok(tally)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8",
+            2,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day04::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Day04::solve_part_one("2-4,6-8\n2-3;4-5"),
+            Err(
+                SolveError::parse(2, 1, "Expected a pair of ranges, like <2-4,6-8>")
+                    .with_text("2-3;4-5")
+            )
+        );
+    }
+}
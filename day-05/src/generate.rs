@@ -0,0 +1,77 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day05;
+
+impl Generate for Day05 {
+    /// A drawing of three to nine stacks, then `size` moves that never empty a stack
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let mut stacks = (0..rng.gen_range(3..=9))
+            .map(|_| {
+                (0..rng.gen_range(1..=8))
+                    .map(|_| rng.gen_range(b'A'..=b'Z') as char)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Top row first, with every row as wide as the numbers below
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut input = String::new();
+        for level in (0..height).rev() {
+            let row = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+        let numbers = (1..=stacks.len())
+            .map(|number| format!(" {} ", number))
+            .collect::<Vec<_>>();
+        input.push_str(&numbers.join(" "));
+        input.push_str("\n\n");
+
+        for _ in 0..size.max(1) {
+            // From a stack of at least two, leaving one behind
+            let sources = (0..stacks.len())
+                .filter(|index| stacks[*index].len() > 1)
+                .collect::<Vec<_>>();
+            let Some(&from) = sources.get(rng.gen_range(0..sources.len().max(1))) else {
+                break;
+            };
+            let mut to = rng.gen_range(0..stacks.len() - 1);
+            if to >= from {
+                to += 1;
+            }
+            let count = rng.gen_range(1..stacks[from].len());
+            let left = stacks[from].len() - count;
+            let moved = stacks[from].split_off(left);
+            stacks[to].extend(moved);
+            input.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day05::seeded(1, 20), Day05::seeded(1, 20));
+        assert_ne!(Day05::seeded(1, 20), Day05::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day05::seeded(seed, 20);
+            if let Err(err) = Day05::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use aoc_common::parse::{lines, unexpected_end, Line};
+use aoc_common::{Solution, SolveError};
+use pest::error::LineColLocation;
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use regex::Regex;
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Supply Stacks
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Procedure<'a>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    /// Stacks and moves from the input
+    fn parse(input: &str) -> Result<Procedure<'_>, SolveError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let mut reader = lines.iter();
+
+        let first = lines
+            .first()
+            .ok_or_else(|| unexpected_end(input, "a drawing of the stacks"))?;
+        let number_of_levels = first.text.len() / 4 + 1;
+        let mut stacks: Vec<Vec<char>> = Vec::new();
+        for _ in 0..number_of_levels {
+            stacks.push(Vec::new());
+        }
+
+        // boxes
+        let re = Regex::new(r"(\s*(\d)\s*)+").unwrap();
+        loop {
+            let line = reader
+                .next()
+                .ok_or_else(|| unexpected_end(input, "a line of stack numbers"))?;
+            if re.is_match(line.text) {
+                break;
+            }
+
+            for (level, chunk) in line
+                .text
+                .chars()
+                .collect::<Vec<char>>()
+                .chunks(4)
+                .enumerate()
+            {
+                let string = chunk.iter().take(3).collect::<String>();
+
+                if string.trim().is_empty() {
+                    // ...
+                } else {
+                    match (chunk.get(1), stacks.get_mut(level)) {
+                        (Some(char), Some(stack)) if char.is_ascii_alphabetic() => {
+                            stack.push(*char)
+                        }
+                        _ => {
+                            return Err(SolveError::parse(
+                                line.number(),
+                                level * 4 + 1,
+                                format!("Invalid crate <{}>", string),
+                            )
+                            .with_text(line.text))
+                        }
+                    }
+                }
+            }
+        }
+
+        // reverse stacks
+        stacks.iter_mut().for_each(|stack| stack.reverse());
+
+        // empty line
+        reader.next();
+
+        // movements
+        let offset = reader
+            .as_slice()
+            .first()
+            .map_or(lines.len(), |line| line.index);
+        let string = reader
+            .map(|line| line.text)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let movements = GrammarParser::parse(Rule::movements, &string).map_err(|err| {
+            let (line, column) = match err.line_col {
+                LineColLocation::Pos(pos) | LineColLocation::Span(pos, _) => pos,
+            };
+            SolveError::parse(
+                offset + line,
+                column,
+                "Expected a movement, like <move 1 from 2 to 1>",
+            )
+            .with_text(err.line())
+        })?;
+
+        let mut moves: Vec<Move> = Vec::new();
+        for movement in movements.flat_map(|movements| movements.into_inner()) {
+            match movement.as_rule() {
+                Rule::movement => {
+                    let line = lines[offset + movement.line_col().0 - 1];
+                    let mut move_ = Move {
+                        count: 0,
+                        from: 0,
+                        to: 0,
+                        line,
+                    };
+                    for i in movement.into_inner() {
+                        match i.as_rule() {
+                            Rule::move_ => move_.count = value(i, &line)?,
+                            Rule::from => move_.from = value(i, &line)?,
+                            Rule::to => move_.to = value(i, &line)?,
+                            _ => unreachable!(),
+                        }
+                    }
+                    moves.push(move_);
+                }
+                Rule::EOI => {}
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part_one(procedure: &Procedure<'_>) -> Result<String, SolveError> {
+        part_1::compute(procedure)
+    }
+
+    fn part_two(procedure: &Procedure<'_>) -> Result<String, SolveError> {
+        part_2::compute(procedure)
+    }
+}
+
+// use pest to read the rest
+#[derive(Parser)]
+#[grammar = "./grammar.pest"]
+pub struct GrammarParser;
+
+/// The starting stacks of crates, and the moves to rearrange them
+#[derive(Debug)]
+pub struct Procedure<'a> {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move<'a>>,
+}
+
+#[derive(Debug)]
+pub struct Move<'a> {
+    count: i32,
+    from: usize,
+    to: usize,
+    line: Line<'a>,
+}
+
+/// Number within a `move_`, `from` or `to` rule
+fn value<T: FromStr>(pair: Pair<Rule>, line: &Line) -> Result<T, SolveError> {
+    let column = pair.line_col().1;
+    let value = pair.into_inner().as_str();
+    value.parse::<T>().map_err(|_| {
+        SolveError::parse(line.number(), column, format!("Invalid number <{}>", value))
+            .with_text(line.text)
+    })
+}
@@ -0,0 +1,77 @@
+use aoc_common::SolveError;
+
+use crate::Procedure;
+
+/// Answer for the parsed input
+pub fn compute(procedure: &Procedure) -> Result<String, SolveError> {
+    let mut stacks = procedure.stacks.clone();
+
+    // Apply Moves
+    for move_ in &procedure.moves {
+        let stack = |number: usize| {
+            if (1..=stacks.len()).contains(&number) {
+                Ok(number - 1)
+            } else {
+                Err(move_
+                    .line
+                    .error(move_.line.text, format!("No stack {}", number)))
+            }
+        };
+        let from: usize = stack(move_.from)?;
+        let to: usize = stack(move_.to)?;
+        let count = move_.count;
+
+        for _ in 0..count {
+            let char = stacks[from].pop().ok_or_else(|| {
+                move_
+                    .line
+                    .error(move_.line.text, "Not enough crates to move")
+            })?;
+            stacks[to].push(char);
+        }
+    }
+
+    // get top of stacks
+    let mut result = String::new();
+    for (number, stack) in stacks.iter().enumerate() {
+        match stack.last() {
+            Some(top) => result.push(*top),
+            None => {
+                return Err(SolveError::no_solution(format!(
+                    "Stack {} is empty",
+                    number + 1
+                )))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        #[rustfmt::skip]
+        let tests = vec![(
+            "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2",
+            "CMZ",
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day05::solve_part_one(input), Ok(expected.to_string()));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::generate::{Generate, Random, Rng, SliceRandom};
+
+use crate::part_2::MARKER_SIZE;
+use crate::Day06;
+
+impl Generate for Day06 {
+    /// A datastream of about `size` characters, with its first start-of-message marker at a random place
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let size = size.max(MARKER_SIZE);
+        // Three letters can never make a marker, so the first is the one put in
+        let before = rng.gen_range(0..=size - MARKER_SIZE);
+        let mut input = (0..before)
+            .map(|_| *[b'a', b'b', b'c'].choose(rng).unwrap() as char)
+            .collect::<String>();
+        let mut letters = (b'a'..=b'z').map(char::from).collect::<Vec<_>>();
+        letters.shuffle(rng);
+        input.extend(&letters[..MARKER_SIZE]);
+        input.extend((input.len()..size).map(|_| rng.gen_range(b'a'..=b'z') as char));
+        input.push('\n');
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day06::seeded(1, 20), Day06::seeded(1, 20));
+        assert_ne!(Day06::seeded(1, 20), Day06::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day06::seeded(seed, 20);
+            if let Err(err) = Day06::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
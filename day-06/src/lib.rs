@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::variant::Variant;
+use aoc_common::{Params, Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Tuning Trouble
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<char>;
+    type PartOne = i32;
+    type PartTwo = i32;
+    const PARAMS: &'static [&'static str] = &["marker_size"];
+
+    /// The datastream buffer
+    fn parse(input: &str) -> Result<Vec<char>, SolveError> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part_one(buffer: &Vec<char>) -> Result<i32, SolveError> {
+        part_1::compute(buffer, &Params::default())
+    }
+
+    fn part_one_with(buffer: &Vec<char>, params: &Params) -> Result<i32, SolveError> {
+        part_1::compute(buffer, params)
+    }
+
+    fn part_two(buffer: &Vec<char>) -> Result<i32, SolveError> {
+        part_2::compute(buffer, &Params::default())
+    }
+
+    fn part_two_with(buffer: &Vec<char>, params: &Params) -> Result<i32, SolveError> {
+        part_2::compute(buffer, params)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "window",
+            part_one: Some(|buffer, params| part_1::window(buffer, params)),
+            part_two: Some(|buffer, params| part_2::window(buffer, params)),
+        }]
+    }
+}
+
+/// Number of characters read once the last `size` of them are all different.
+fn marker(buffer: &[char], size: usize) -> Result<i32, SolveError> {
+    if size == 0 {
+        return Err(SolveError::param("marker_size must be at least 1"));
+    }
+    let chars = buffer.iter().copied();
+    let mut stack = Vec::with_capacity(size);
+    for (index, chr) in chars.enumerate() {
+        if stack.len() == size {
+            stack.remove(0);
+        }
+        stack.push(chr);
+        let set: HashSet<char> = HashSet::from_iter(stack.iter().cloned());
+        if set.len() == size {
+            return Ok(index as i32 + 1);
+        }
+    }
+    Err(no_marker(size))
+}
+
+/// Same as [`marker`], sliding a window along that counts each character in it, rather than
+/// collecting every window into a set.
+fn marker_window(buffer: &[char], size: usize) -> Result<i32, SolveError> {
+    if size == 0 {
+        return Err(SolveError::param("marker_size must be at least 1"));
+    }
+    let mut counts = HashMap::new();
+    for (index, chr) in buffer.iter().enumerate() {
+        *counts.entry(chr).or_insert(0) += 1;
+        if index >= size {
+            let left = &buffer[index - size];
+            match counts.get_mut(left) {
+                Some(1) => {
+                    counts.remove(left);
+                }
+                Some(count) => *count -= 1,
+                None => unreachable!("every character in the window is counted"),
+            }
+        }
+        if counts.len() == size {
+            return Ok(index as i32 + 1);
+        }
+    }
+    Err(no_marker(size))
+}
+
+fn no_marker(size: usize) -> SolveError {
+    SolveError::no_solution(format!("No {} distinct characters in a row", size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::Generate;
+    use aoc_common::variant::check;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_variants() {
+        let mut params = Params::default();
+        let inputs = (0..50).map(|seed| Day06::seeded(seed, 100));
+        for input in inputs.chain([
+            "abab".to_string(),
+            include_str!("../example.txt").to_string(),
+        ]) {
+            for part in [1, 2] {
+                let checked = check::<Day06>(part, &input, &params).unwrap();
+                assert!(checked.agrees(), "Part {}:\n{}\n{}", part, checked, input);
+            }
+        }
+        params.set("marker_size=1").unwrap();
+        assert_eq!(marker_window(&['a', 'a'], 1), Ok(1));
+        assert!(check::<Day06>(1, "aab", &params).unwrap().agrees());
+    }
+}
@@ -0,0 +1,46 @@
+use aoc_common::{Params, SolveError};
+
+use crate::{marker, marker_window};
+
+const MARKER_SIZE: usize = 4;
+
+/// Answer for the parsed input
+pub fn compute(buffer: &[char], params: &Params) -> Result<i32, SolveError> {
+    marker(buffer, params.get("marker_size", MARKER_SIZE)?)
+}
+
+/// Answer for the parsed input, with a sliding window
+pub fn window(buffer: &[char], params: &Params) -> Result<i32, SolveError> {
+    marker_window(buffer, params.get("marker_size", MARKER_SIZE)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(Day06::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(
+            Day06::solve_part_one("abab"),
+            Err(SolveError::no_solution("No 4 distinct characters in a row"))
+        );
+    }
+}
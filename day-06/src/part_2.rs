@@ -0,0 +1,52 @@
+use aoc_common::{Params, SolveError};
+
+use crate::{marker, marker_window};
+
+pub(crate) const MARKER_SIZE: usize = 14;
+
+/// Answer for the parsed input
+pub fn compute(buffer: &[char], params: &Params) -> Result<i32, SolveError> {
+    marker(buffer, params.get("marker_size", MARKER_SIZE)?)
+}
+
+/// Answer for the parsed input, with a sliding window
+pub fn window(buffer: &[char], params: &Params) -> Result<i32, SolveError> {
+    marker_window(buffer, params.get("marker_size", MARKER_SIZE)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use aoc_common::{Params, Solution, SolveError};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(Day06::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_marker_size() {
+        let mut params = Params::default();
+        params.set("marker_size=4").unwrap();
+        assert_eq!(
+            Day06::solve_part_two_with("bvwbjplbgvbhsrlpgdmjqwftvncz", &params),
+            Ok(5)
+        );
+        params.set("marker_size=0").unwrap();
+        assert!(matches!(
+            Day06::solve_part_two_with("bvwbjplbgvbhsrlpgdmjqwftvncz", &params),
+            Err(SolveError::Param(_))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day07;
+
+/// Directory of the made-up filesystem, with the index of each subdirectory.
+#[derive(Default)]
+struct Dir {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(u32, String)>,
+    // Names of both
+    names: HashSet<String>,
+}
+
+impl Generate for Day07 {
+    /// Browsing a filesystem of `size` files, filling between 45 and 65 million of its 70
+    /// million, so there is always a directory to delete
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let size = size.max(1);
+        let mut dirs = vec![Dir::default()];
+        // Each directory in a random one made before it, with a name unique among its siblings
+        for index in 1..rng.gen_range(1..=size / 3 + 1) {
+            let parent = rng.gen_range(0..index);
+            let name = unique_name(rng, &mut dirs[parent].names, false);
+            dirs[parent].dirs.push(index);
+            dirs.push(Dir {
+                name,
+                ..Dir::default()
+            });
+        }
+
+        let used = rng.gen_range(45_000_000..=65_000_000u64);
+        let weights = (0..size)
+            .map(|_| rng.gen_range(1..=1000))
+            .collect::<Vec<u64>>();
+        let total = weights.iter().sum::<u64>();
+        for weight in weights {
+            let dir = rng.gen_range(0..dirs.len());
+            let name = unique_name(rng, &mut dirs[dir].names, true);
+            let size = (used * weight / total).max(1) as u32;
+            dirs[dir].files.push((size, name));
+        }
+
+        let mut input = String::new();
+        browse(&dirs, 0, &mut input);
+        input
+    }
+}
+
+/// `cd` into a directory, `ls` it, then browse each subdirectory and come back.
+fn browse(dirs: &[Dir], index: usize, input: &mut String) {
+    let dir = &dirs[index];
+    if index == 0 {
+        input.push_str("$ cd /\n");
+    } else {
+        input.push_str(&format!("$ cd {}\n", dir.name));
+    }
+    input.push_str("$ ls\n");
+    for sub in &dir.dirs {
+        input.push_str(&format!("dir {}\n", dirs[*sub].name));
+    }
+    for (size, name) in &dir.files {
+        input.push_str(&format!("{} {}\n", size, name));
+    }
+    for sub in &dir.dirs {
+        browse(dirs, *sub, input);
+        input.push_str("$ cd ..\n");
+    }
+}
+
+/// A few lowercase letters, with an extension for some files, not already in `taken`.
+fn unique_name(rng: &mut Random, taken: &mut HashSet<String>, file: bool) -> String {
+    loop {
+        let mut name = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if file && rng.gen_bool(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.gen_range(b'a'..=b'z') as char));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day07::seeded(1, 20), Day07::seeded(1, 20));
+        assert_ne!(Day07::seeded(1, 20), Day07::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day07::seeded(seed, 20);
+            if let Err(err) = Day07::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+        assert!(Day07::solve_part_two(&Day07::seeded(3, 50)).unwrap() > 0);
+    }
+}
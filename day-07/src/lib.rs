@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use aoc_common::parse::lines;
+use aoc_common::{Params, Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// No Space Left On Device
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<File>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    const PARAMS: &'static [&'static str] = &["filesystem_size", "minimum_system_size"];
+
+    /// Every file listed in the terminal output, with its full path
+    fn parse(input: &str) -> Result<Vec<File>, SolveError> {
+        let mut files: Vec<File> = Vec::new();
+
+        let mut environ = Environment::new();
+        for line in lines(input) {
+            let text = line.text.trim();
+            // Command
+            if let Some(command) = text.strip_prefix("$ ") {
+                let command = line.parse_part::<Command>(command)?;
+                match command {
+                    Command::Cd(path) => {
+                        if path.as_os_str() == ".." {
+                            environ.cwd.pop();
+                        } else {
+                            environ.cwd.push(path);
+                        }
+                    }
+                    Command::Ls => {}
+                }
+            // ls Ouput
+            } else {
+                // Directory: dir <path>
+                if text.starts_with("dir ") {
+                    // ...
+                    // File: <size> <file>
+                } else {
+                    let mut file = line.parse::<File>()?;
+                    file.path = environ.cwd.join(&file.path);
+                    files.push(file);
+                }
+            }
+        }
+
+        Ok(files)
+    }
+
+    fn part_one(files: &Vec<File>) -> Result<u64, SolveError> {
+        part_1::compute(files)
+    }
+
+    fn part_two(files: &Vec<File>) -> Result<u64, SolveError> {
+        part_2::compute(files, &Params::default())
+    }
+
+    fn part_two_with(files: &Vec<File>, params: &Params) -> Result<u64, SolveError> {
+        part_2::compute(files, params)
+    }
+}
+
+/// Total size of every directory, including its subdirectories.
+fn dir_sizes(files: &[File]) -> Result<HashMap<PathBuf, u64>, SolveError> {
+    // HashMap of directories and their sizes
+    let mut dirs: HashMap<PathBuf, u64> = HashMap::new();
+    for file in files {
+        let dir = file.path.parent().ok_or_else(|| {
+            SolveError::no_solution(format!("File <{}> is not in a directory", file))
+        })?;
+        *dirs.entry(dir.to_owned()).or_default() += u64::from(file.size);
+    }
+
+    // Add children sizes to parents
+    for (dir, size) in dirs.clone().iter() {
+        let mut parent = dir.parent();
+        while let Some(parent_dir) = parent {
+            *dirs.entry(parent_dir.to_owned()).or_default() += size;
+            parent = parent_dir.parent();
+        }
+    }
+
+    Ok(dirs)
+}
+
+#[derive(Debug)]
+pub struct Environment {
+    pub cwd: PathBuf,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self {
+            cwd: PathBuf::from("/"),
+        }
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, parse_display::FromStr)]
+#[display("{size} {path}")]
+pub struct File {
+    size: u32,
+    path: PathBuf,
+}
+
+// Paths have no `Display`, so these are written out by hand
+impl fmt::Display for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.size, self.path.display())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, parse_display::FromStr)]
+pub enum Command {
+    #[display("cd {0}")]
+    Cd(PathBuf),
+    #[display("ls")]
+    Ls,
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Cd(path) => write!(f, "cd {}", path.display()),
+            Command::Ls => write!(f, "ls"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use proptest::prelude::*;
+
+    /// Names and paths as they appear in the terminal output
+    fn path() -> impl Strategy<Value = PathBuf> {
+        prop_oneof![
+            Just(PathBuf::from("/")),
+            Just(PathBuf::from("..")),
+            "[a-z]{1,8}(\\.[a-z]{1,3})?".prop_map(PathBuf::from),
+        ]
+    }
+
+    fn command() -> impl Strategy<Value = Command> {
+        prop_oneof![path().prop_map(Command::Cd), Just(Command::Ls)]
+    }
+
+    proptest! {
+        #[test]
+        fn test_command_round_trip(command in command()) {
+            prop_assert_eq!(command.to_string().parse::<Command>(), Ok(command));
+        }
+
+        #[test]
+        fn test_file_round_trip(size: u32, path in path()) {
+            let file = File { size, path };
+            prop_assert_eq!(file.to_string().parse::<File>(), Ok(file));
+        }
+    }
+}
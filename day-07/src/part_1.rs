@@ -0,0 +1,73 @@
+use aoc_common::SolveError;
+
+use crate::{dir_sizes, File};
+
+/// Answer for the parsed input
+pub fn compute(files: &[File]) -> Result<u64, SolveError> {
+    let dirs = dir_sizes(files)?;
+
+    let mut total = 0;
+    for (_dir, size) in dirs.iter() {
+        if *size <= 100000 {
+            total += size;
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+            95437,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day07::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Day07::solve_part_one("$ cd /\n$ rm a"),
+            Err(SolveError::parse(2, 3, "Invalid Command: <rm a>").with_text("$ rm a"))
+        );
+        assert_eq!(
+            Day07::solve_part_one("$ cd ..\n100 /"),
+            Err(SolveError::no_solution(
+                "File <100 /> is not in a directory"
+            ))
+        );
+    }
+}
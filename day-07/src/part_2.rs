@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use aoc_common::{Params, SolveError};
+
+use crate::{dir_sizes, File};
+
+const FILESYSTEM_SIZE: u64 = 70_000_000;
+const MINIMUM_SYSTEM_SIZE: u64 = 30_000_000;
+
+/// Answer for the parsed input
+pub fn compute(files: &[File], params: &Params) -> Result<u64, SolveError> {
+    let filesystem_size: u64 = params.get("filesystem_size", FILESYSTEM_SIZE)?;
+    let minimum_system_size: u64 = params.get("minimum_system_size", MINIMUM_SYSTEM_SIZE)?;
+    let dirs = dir_sizes(files)?;
+
+    let current_disk_size = dirs
+        .get(&PathBuf::from("/"))
+        .ok_or_else(|| SolveError::no_solution("No files listed"))?;
+    let unused_disk_size = filesystem_size.saturating_sub(*current_disk_size);
+    let removal_required = minimum_system_size.saturating_sub(unused_disk_size);
+
+    let mut smallest = 0;
+    for (_dir, size) in dirs.iter() {
+        if *size >= removal_required && (smallest == 0 || *size < smallest) {
+            smallest = *size;
+        }
+    }
+
+    Ok(smallest)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use aoc_common::{Params, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+            24933642,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day07::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_filesystem_size() {
+        // Room for the update already, so the smallest directory is enough
+        let mut params = Params::default();
+        params.set("filesystem_size=78381165").unwrap();
+        assert_eq!(
+            Day07::solve_part_two_with(include_str!("../example.txt"), &params),
+            Ok(584)
+        );
+    }
+}
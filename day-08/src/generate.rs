@@ -0,0 +1,40 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day08;
+
+impl Generate for Day08 {
+    /// A square forest `size` trees wide
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let mut row = (0..side)
+                    .map(|_| char::from_digit(rng.gen_range(0..10), 10).unwrap())
+                    .collect::<String>();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day08::seeded(1, 20), Day08::seeded(1, 20));
+        assert_ne!(Day08::seeded(1, 20), Day08::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day08::seeded(seed, 20);
+            if let Err(err) = Day08::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use aoc_common::picture::{Palette, Rgb};
+use aoc_common::variant::Variant;
+use aoc_common::{Grid, Point, Simulation, Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Treetop Tree House
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid<Tree>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Create X Y Coordinate System
+    fn parse(input: &str) -> Result<Grid<Tree>, SolveError> {
+        Ok(Grid::parse(input, |c| c.to_digit(10))?)
+    }
+
+    fn part_one(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+        part_1::compute(forest)
+    }
+
+    fn part_two(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+        part_2::compute(forest)
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "sweep",
+            part_one: Some(|forest, _| part_1::sweep(forest)),
+            part_two: Some(|forest, _| part_2::sweep(forest)),
+        }]
+    }
+}
+
+pub type Tree = u32;
+
+/// Whether every tree between this one and an edge is shorter, so edges are always visible.
+fn visible(forest: &Grid<Tree>, point: Point) -> bool {
+    let tree = &forest[point];
+    Point::ORTHOGONAL
+        .iter()
+        .any(|direction| forest.ray(point, *direction).all(|other| other < tree))
+}
+
+/// Product of how many trees can be seen up, down, left and right of a tree.
+fn scenic_score(forest: &Grid<Tree>, point: Point) -> i32 {
+    let tree = &forest[point];
+    // Check all trees to the up, down, left, right
+    let mut score = 1;
+    for direction in Point::ORTHOGONAL {
+        let mut view = 0;
+        for other in forest.ray(point, direction) {
+            view += 1;
+            if other >= tree {
+                break;
+            }
+        }
+        score *= view;
+    }
+    score
+}
+
+/// Every line of sight from an edge into the forest: each row from both sides, and each column from both ends.
+fn sight_lines(forest: &Grid<Tree>) -> Vec<Vec<Point>> {
+    let (width, height) = (forest.width() as i32, forest.height() as i32);
+    let rows = (0..height).map(|y| (0..width).map(|x| Point::new(x, y)).collect::<Vec<_>>());
+    let columns = (0..width).map(|x| (0..height).map(|y| Point::new(x, y)).collect::<Vec<_>>());
+    rows.chain(columns)
+        .flat_map(|line| {
+            let reversed = line.iter().rev().copied().collect();
+            [line, reversed]
+        })
+        .collect()
+}
+
+/// Same as [`visible`] for every tree, keeping the tallest tree so far along each line of sight.
+fn visible_trees(forest: &Grid<Tree>) -> Grid<bool> {
+    let mut visible = forest.map(|_| false);
+    for line in sight_lines(forest) {
+        let mut tallest = None;
+        for point in line {
+            let tree = forest[point];
+            if tallest.is_none_or(|tallest| tree > tallest) {
+                visible[point] = true;
+                tallest = Some(tree);
+            }
+        }
+    }
+    visible
+}
+
+/// Same as [`scenic_score`] for every tree, keeping a stack of the trees along each line of sight
+/// that are still tall enough to block the view of the next.
+fn scenic_scores(forest: &Grid<Tree>) -> Grid<i32> {
+    let mut scores = forest.map(|_| 1);
+    for line in sight_lines(forest) {
+        // Index along the line and height, getting shorter towards the top
+        let mut blocking: Vec<(usize, Tree)> = Vec::new();
+        for (index, point) in line.into_iter().enumerate() {
+            let tree = forest[point];
+            while blocking.last().is_some_and(|(_, other)| *other < tree) {
+                blocking.pop();
+            }
+            // Back to the tree blocking the view, or the edge
+            let view = blocking.last().map_or(index, |(other, _)| index - other);
+            scores[point] *= view as i32;
+            blocking.push((index, tree));
+        }
+    }
+    scores
+}
+
+/// A tree of the forest, and what the survey has found out about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spot {
+    Unchecked(Tree),
+    Hidden(Tree),
+    Visible(Tree),
+    /// The most scenic tree so far
+    Best,
+}
+
+/// Shades of grey for unchecked trees, brown for hidden and green for visible, taller being lighter, then yellow
+const fn shades() -> [Rgb; 31] {
+    let mut colours = [[255, 220, 40]; 31];
+    let mut height = 0;
+    while height < 10 {
+        let light = 40 + 20 * height as u8;
+        colours[height] = [light, light, light];
+        colours[10 + height] = [light, light / 3 * 2, light / 3];
+        colours[20 + height] = [light / 4, light, light / 3];
+        height += 1;
+    }
+    colours
+}
+
+impl Palette for Spot {
+    const COLOURS: &'static [Rgb] = &shades();
+
+    fn colour(&self) -> u8 {
+        match self {
+            Spot::Unchecked(tree) => (*tree).min(9) as u8,
+            Spot::Hidden(tree) => 10 + (*tree).min(9) as u8,
+            Spot::Visible(tree) => 20 + (*tree).min(9) as u8,
+            Spot::Best => 30,
+        }
+    }
+}
+
+/// Survey of the forest, checking one tree per step, row by row, for whether it is visible and how scenic it is.
+#[derive(Debug, Clone)]
+pub struct Survey<'a> {
+    forest: &'a Grid<Tree>,
+    spots: Grid<Spot>,
+    checked: usize,
+    visible: usize,
+    // The most scenic tree so far, and its score
+    best: Option<(Point, i32)>,
+}
+
+impl<'a> Survey<'a> {
+    pub fn new(forest: &'a Grid<Tree>) -> Self {
+        Self {
+            forest,
+            spots: forest.map(|tree| Spot::Unchecked(*tree)),
+            checked: 0,
+            visible: 0,
+            best: None,
+        }
+    }
+
+    /// Number of trees visible from outside the forest, so far.
+    pub fn visible(&self) -> usize {
+        self.visible
+    }
+
+    /// Highest scenic score so far.
+    pub fn best_score(&self) -> i32 {
+        self.best.map_or(0, |(_, score)| score)
+    }
+}
+
+impl Simulation for Survey<'_> {
+    type Snapshot = Grid<Spot>;
+
+    /// Check the next tree
+    fn step(&mut self) -> bool {
+        let width = self.forest.width();
+        if self.checked >= width * self.forest.height() {
+            return false;
+        }
+        let point = Point::new((self.checked % width) as i32, (self.checked / width) as i32);
+        let tree = self.forest[point];
+        self.spots[point] = if visible(self.forest, point) {
+            self.visible += 1;
+            Spot::Visible(tree)
+        } else {
+            Spot::Hidden(tree)
+        };
+        let score = scenic_score(self.forest, point);
+        if score > self.best_score() {
+            self.best = Some((point, score));
+        }
+        self.checked += 1;
+        true
+    }
+
+    fn steps(&self) -> usize {
+        self.checked
+    }
+
+    /// The trees, with the most scenic one so far marked
+    fn snapshot(&self) -> Grid<Spot> {
+        let mut spots = self.spots.clone();
+        if let Some((point, _)) = self.best {
+            spots[point] = Spot::Best;
+        }
+        spots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::Generate;
+    use aoc_common::variant::check;
+    use aoc_common::Params;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_variants() {
+        let params = Params::default();
+        let inputs = (0..50).map(|seed| Day08::seeded(seed, 1 + seed as usize % 20));
+        for input in inputs.chain([include_str!("../example.txt").to_string()]) {
+            for part in [1, 2] {
+                let checked = check::<Day08>(part, &input, &params).unwrap();
+                assert!(checked.agrees(), "Part {}:\n{}\n{}", part, checked, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::{Grid, SolveError};
+
+use crate::{visible, visible_trees, Tree};
+
+/// Answer for the parsed input
+pub fn compute(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+    Ok(forest
+        .points()
+        .filter(|point| visible(forest, *point))
+        .count() as i32)
+}
+
+/// Answer for the parsed input, sweeping along each line of sight
+pub fn sweep(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+    Ok(visible_trees(forest)
+        .iter()
+        .filter(|(_, seen)| **seen)
+        .count() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day08, Spot, Survey};
+    use aoc_common::picture::Paint;
+    use aoc_common::{Point, Simulation, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "30373
+25512
+65332
+33549
+35390",
+            21,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day08::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_survey() {
+        let forest = Day08::parse(include_str!("../example.txt")).unwrap();
+        let mut survey = Survey::new(&forest);
+        assert_eq!(survey.run_until(|survey| survey.steps() == 7), 7);
+        // The first row, then the left edge and a tall tree of the second, which is the first with a view
+        assert_eq!(survey.visible(), 7);
+        let spots = survey.snapshot();
+        assert_eq!(spots[Point::new(0, 1)], Spot::Visible(2));
+        assert_eq!(spots[Point::new(1, 1)], Spot::Best);
+        assert_eq!(spots[Point::new(2, 1)], Spot::Unchecked(5));
+        assert_eq!(&spots.paint().pixels()[4..8], &[23, 22, 30, 5]);
+        assert_eq!(survey.run(), 18);
+        assert_eq!(survey.visible(), 21);
+        assert_eq!(survey.best_score(), 8);
+        assert_eq!(survey.snapshot()[Point::new(2, 3)], Spot::Best);
+    }
+}
@@ -0,0 +1,41 @@
+use aoc_common::{Grid, SolveError};
+
+use crate::{scenic_score, scenic_scores, Tree};
+
+/// Answer for the parsed input
+pub fn compute(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+    let best_score = forest
+        .points()
+        .map(|point| scenic_score(forest, point))
+        .max();
+    Ok(best_score.unwrap_or(0))
+}
+
+/// Answer for the parsed input, sweeping along each line of sight
+pub fn sweep(forest: &Grid<Tree>) -> Result<i32, SolveError> {
+    let best_score = scenic_scores(forest).iter().map(|(_, score)| *score).max();
+    Ok(best_score.unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "30373
+25512
+65332
+33549
+35390",
+            8,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day08::solve_part_two(input), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_common::generate::{Generate, Random, Rng, SliceRandom};
+
+use crate::Day09;
+
+impl Generate for Day09 {
+    /// `size` movements of the head, of up to 20 steps each
+    fn generate(rng: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let direction = ["U", "D", "L", "R"].choose(rng).unwrap();
+                format!("{} {}\n", direction, rng.gen_range(1..=20))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day09::seeded(1, 20), Day09::seeded(1, 20));
+        assert_ne!(Day09::seeded(1, 20), Day09::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day09::seeded(seed, 20);
+            if let Err(err) = Day09::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
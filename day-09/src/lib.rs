@@ -0,0 +1,397 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+use aoc_common::parse::lines;
+use aoc_common::picture::{Paint, Palette, Picture, Rgb};
+use aoc_common::simulation::{Draw, Frame};
+use aoc_common::{Grid, Params, Point, Simulation, Solution, SolveError};
+use parse_display::{Display, FromStr};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Rope Bridge
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Movement>;
+    type PartOne = i32;
+    type PartTwo = i32;
+    const PARAMS: &'static [&'static str] = &["knot_count"];
+
+    /// Every movement of the head
+    fn parse(input: &str) -> Result<Vec<Movement>, SolveError> {
+        lines(input).map(|line| line.parse::<Movement>()).collect()
+    }
+
+    fn part_one(movements: &Vec<Movement>) -> Result<i32, SolveError> {
+        part_1::compute(movements, &Params::default())
+    }
+
+    fn part_one_with(movements: &Vec<Movement>, params: &Params) -> Result<i32, SolveError> {
+        part_1::compute(movements, params)
+    }
+
+    fn part_two(movements: &Vec<Movement>) -> Result<i32, SolveError> {
+        part_2::compute(movements, &Params::default())
+    }
+
+    fn part_two_with(movements: &Vec<Movement>, params: &Params) -> Result<i32, SolveError> {
+        part_2::compute(movements, params)
+    }
+}
+
+/// Rope knot with head and optional tail at x,y coordinates
+#[derive(Debug, Clone)]
+struct Knot {
+    head: Point,
+    tail: Rc<RefCell<Option<Knot>>>,
+    history: HashSet<Point>,
+}
+
+impl Knot {
+    fn new() -> Self {
+        let mut history = HashSet::new();
+        history.insert(Point::ORIGIN);
+        Self {
+            head: Point::ORIGIN,
+            tail: Rc::new(RefCell::new(None)),
+            history,
+        }
+    }
+    fn add_tail(&mut self) {
+        let mut last_tail = self.tail.borrow_mut();
+        if last_tail.is_none() {
+            *last_tail = Some(Knot::new());
+        } else {
+            last_tail.as_mut().unwrap().add_tail();
+        }
+    }
+}
+
+impl Knot {
+    /// Position of this knot and each one behind it
+    fn positions(&self) -> Vec<Point> {
+        let mut positions = vec![self.head];
+        if let Some(tail) = self.tail.borrow().as_ref() {
+            positions.extend(tail.positions());
+        }
+        positions
+    }
+
+    fn last(&self) -> Knot {
+        match self.tail.borrow().as_ref() {
+            Some(knot) => knot.last(),
+            None => self.clone(),
+        }
+    }
+}
+
+/// Movement
+impl Knot {
+    /// Move the rope
+    fn move_head(&mut self, movement: &Movement) {
+        self.head += movement.direction();
+        self.history.insert(self.head);
+        self.move_tail();
+    }
+    /// Move the tail, which follows the head
+    fn move_tail(&mut self) {
+        if let Some(tail) = self.tail.borrow_mut().as_mut() {
+            let distance = self.head - tail.head;
+            if distance.x.abs() <= 1 && distance.y.abs() <= 1 {
+                return;
+            }
+            // Step one unit towards the head, diagonally if not in the same row or column
+            tail.head += distance.signum();
+            tail.history.insert(tail.head);
+            tail.move_tail();
+        }
+    }
+}
+
+impl fmt::Display for Knot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = String::new();
+
+        let mut max_x = 0;
+        let mut max_y = 0;
+        for point in &self.history {
+            if point.x > max_x {
+                max_x = point.x;
+            }
+            if point.y > max_y {
+                max_y = point.y;
+            }
+        }
+        if max_x > max_y {
+            max_y = max_x;
+        } else {
+            max_x = max_y;
+        }
+
+        for y in (0..=max_y + 1).rev() {
+            for x in 0..=max_x + 1 {
+                let point = Point::new(x, y);
+                if self.head == point {
+                    buffer.push('H');
+                } else if self.tail.borrow().is_some()
+                    && self.tail.borrow().as_ref().unwrap().head == point
+                {
+                    buffer.push('T');
+                } else if self.history.contains(&point) {
+                    buffer.push('#');
+                } else {
+                    buffer.push('.');
+                }
+            }
+            buffer.push('\n');
+        }
+
+        write!(f, "{}", buffer)
+    }
+}
+
+/// A rope whose head follows a list of movements, one unit per step.
+#[derive(Debug)]
+pub struct Rope<'a> {
+    knot: Knot,
+    movements: &'a [Movement],
+    // Current movement, and how far along it the head has moved
+    movement: usize,
+    moved: i32,
+    steps: usize,
+    corners: (Point, Point),
+}
+
+impl<'a> Rope<'a> {
+    /// Rope of `knot_count` knots, at least one, all starting at the origin.
+    pub fn new(movements: &'a [Movement], knot_count: usize) -> Result<Self, SolveError> {
+        if knot_count == 0 {
+            return Err(SolveError::param("knot_count must be at least 1"));
+        }
+        let mut knot = Knot::new();
+        for _ in 1..knot_count {
+            knot.add_tail();
+        }
+        // Every knot follows the head, so stays within the area it covers
+        let mut head = Point::ORIGIN;
+        let mut corners = (head, head);
+        for movement in movements {
+            head += movement.direction() * movement.distance();
+            corners.0 = Point::new(corners.0.x.min(head.x), corners.0.y.min(head.y));
+            corners.1 = Point::new(corners.1.x.max(head.x), corners.1.y.max(head.y));
+        }
+        Ok(Self {
+            knot,
+            movements,
+            movement: 0,
+            moved: 0,
+            steps: 0,
+            corners,
+        })
+    }
+
+    /// Number of points the last knot has visited.
+    pub fn tail_visits(&self) -> usize {
+        self.knot.last().history.len()
+    }
+}
+
+/// Positions of a rope's knots, head first, and the points its last knot has visited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RopeSnapshot {
+    pub knots: Vec<Point>,
+    pub visited: HashSet<Point>,
+    /// Smallest and largest coordinates of any knot over all the movements
+    pub corners: (Point, Point),
+}
+
+impl Simulation for Rope<'_> {
+    type Snapshot = RopeSnapshot;
+
+    /// Move the head one unit
+    fn step(&mut self) -> bool {
+        // Along the current movement, moving on to the next once it is finished
+        while let Some(movement) = self.movements.get(self.movement) {
+            if self.moved < movement.distance() {
+                self.knot.move_head(movement);
+                self.moved += 1;
+                self.steps += 1;
+                return true;
+            }
+            self.movement += 1;
+            self.moved = 0;
+        }
+        false
+    }
+
+    fn steps(&self) -> usize {
+        self.steps
+    }
+
+    fn snapshot(&self) -> RopeSnapshot {
+        RopeSnapshot {
+            knots: self.knot.positions(),
+            visited: self.knot.last().history,
+            corners: self.corners,
+        }
+    }
+}
+
+impl Draw for RopeSnapshot {
+    /// The head `H` and knots behind it as in the puzzle, over the points the tail has visited `#`
+    fn draw(&self) -> Frame {
+        // Up is positive y in the puzzle, but down the screen
+        let screen = |point: &Point| Point::new(point.x, -point.y);
+        let points = self.visited.iter().chain(&self.knots).map(screen);
+        let (min, max) = points.fold((Point::ORIGIN, Point::ORIGIN), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        });
+
+        let size = max - min + Point::new(1, 1);
+        let mut rows = vec![vec!['.'; size.x as usize]; size.y as usize];
+        let mut set = |point: &Point, c: char| {
+            let point = screen(point) - min;
+            rows[point.y as usize][point.x as usize] = c;
+        };
+        for point in &self.visited {
+            set(point, '#');
+        }
+        set(&Point::ORIGIN, 's');
+        // From the tail forwards, so knots in front are drawn on top
+        for (index, knot) in self.knots.iter().enumerate().rev() {
+            let c = match index {
+                0 => 'H',
+                1 if self.knots.len() == 2 => 'T',
+                1..=9 => char::from_digit(index as u32, 10).unwrap(),
+                _ => '*',
+            };
+            set(knot, c);
+        }
+
+        Frame {
+            rows: rows.into_iter().map(String::from_iter).collect(),
+            origin: min,
+            focus: screen(&self.knots[0]),
+            caption: format!(
+                "{} knots, the tail has visited {} points",
+                self.knots.len(),
+                self.visited.len()
+            ),
+        }
+    }
+}
+
+/// What is at a point of a painted rope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spot {
+    Empty,
+    Visited,
+    Start,
+    Knot,
+    Head,
+}
+
+impl Palette for Spot {
+    /// Dark ground, blue visited points, green start, orange knots and a red head
+    const COLOURS: &'static [Rgb] = &[
+        [24, 24, 40],
+        [70, 110, 190],
+        [60, 180, 90],
+        [240, 160, 50],
+        [230, 50, 40],
+    ];
+
+    fn colour(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl Paint for RopeSnapshot {
+    /// Every point any knot reaches, with up at the top like the puzzle
+    fn paint(&self) -> Picture {
+        let (min, max) = self.corners;
+        let mut grid = Grid::new(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            Spot::Empty,
+        );
+        let mut set = |point: &Point, spot: Spot| {
+            grid[Point::new(point.x - min.x, max.y - point.y)] = spot;
+        };
+        for point in &self.visited {
+            set(point, Spot::Visited);
+        }
+        set(&Point::ORIGIN, Spot::Start);
+        for knot in self.knots.iter().skip(1) {
+            set(knot, Spot::Knot);
+        }
+        set(&self.knots[0], Spot::Head);
+        grid.paint()
+    }
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+pub enum Movement {
+    #[display("U {0}")]
+    Up(i32),
+    #[display("D {0}")]
+    Down(i32),
+    #[display("L {0}")]
+    Left(i32),
+    #[display("R {0}")]
+    Right(i32),
+}
+
+impl Movement {
+    /// Unit step of the movement, with up being positive y
+    fn direction(&self) -> Point {
+        use Movement::*;
+        match self {
+            Up(_) => Point::new(0, 1),
+            Down(_) => Point::new(0, -1),
+            Right(_) => Point::RIGHT,
+            Left(_) => Point::LEFT,
+        }
+    }
+    fn distance(&self) -> i32 {
+        use Movement::*;
+        match self {
+            Up(distance) => *distance,
+            Down(distance) => *distance,
+            Right(distance) => *distance,
+            Left(distance) => *distance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use proptest::prelude::*;
+
+    fn movement() -> impl Strategy<Value = Movement> {
+        prop_oneof![
+            any::<i32>().prop_map(Movement::Up),
+            any::<i32>().prop_map(Movement::Down),
+            any::<i32>().prop_map(Movement::Left),
+            any::<i32>().prop_map(Movement::Right),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_movement_round_trip(movement in movement()) {
+            prop_assert_eq!(movement.to_string().parse::<Movement>(), Ok(movement));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_common::{Params, Simulation, SolveError};
+
+use crate::{Movement, Rope};
+
+/// The rope, two knots long unless `knot_count` is set
+pub fn rope<'a>(movements: &'a [Movement], params: &Params) -> Result<Rope<'a>, SolveError> {
+    Rope::new(movements, params.get("knot_count", 2)?)
+}
+
+/// Answer for the parsed input
+pub fn compute(movements: &[Movement], params: &Params) -> Result<i32, SolveError> {
+    let mut rope = rope(movements, params)?;
+    rope.run();
+    Ok(rope.tail_visits() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day09, Rope};
+    use aoc_common::picture::Paint;
+    use aoc_common::simulation::Draw;
+    use aoc_common::{Params, Point, Simulation, Solution, SolveError};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn simple_movement() {
+        let input = "R 4";
+        let answer = Day09::solve_part_one(input);
+        assert_eq!(answer, Ok(4));
+    }
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2",
+            13,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day09::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_knot_count() {
+        let input = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+        let mut params = Params::default();
+        params.set("knot_count=10").unwrap();
+        assert_eq!(Day09::solve_part_one_with(input, &params), Ok(36));
+        params.set("knot_count=0").unwrap();
+        assert!(matches!(
+            Day09::solve_part_one_with(input, &params),
+            Err(SolveError::Param(_))
+        ));
+
+        let mut params = Params::default();
+        params.set("knot_cout=3").unwrap();
+        assert_eq!(
+            Day09::solve_part_one_with(input, &params),
+            Err(SolveError::param(
+                "<knot_cout> is not a parameter, expected knot_count"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rope() {
+        let movements = Day09::parse("R 4\nU 4").unwrap();
+        let mut rope = Rope::new(&movements, 2).unwrap();
+        assert_eq!(rope.run_until(|rope| rope.steps() == 4), 4);
+        assert_eq!(
+            rope.snapshot().knots,
+            vec![Point::new(4, 0), Point::new(3, 0)]
+        );
+        assert_eq!(rope.snapshots().count(), 5);
+        assert_eq!(
+            rope.snapshot().knots,
+            vec![Point::new(4, 4), Point::new(4, 3)]
+        );
+        assert_eq!(rope.tail_visits(), 7);
+
+        let frame = rope.snapshot().draw();
+        assert_eq!(
+            frame.rows,
+            vec!["....H", "....T", "....#", "....#", "s###."]
+        );
+        assert_eq!(frame.origin, Point::new(0, -4));
+        assert_eq!(frame.focus, Point::new(4, -4));
+
+        let picture = rope.snapshot().paint();
+        assert_eq!((picture.width(), picture.height()), (5, 5));
+        assert_eq!(
+            picture.pixels(),
+            &[0, 0, 0, 0, 4, 0, 0, 0, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0]
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::{Params, Simulation, SolveError};
+
+use crate::{Movement, Rope};
+
+/// The rope, ten knots long unless `knot_count` is set
+pub fn rope<'a>(movements: &'a [Movement], params: &Params) -> Result<Rope<'a>, SolveError> {
+    Rope::new(movements, params.get("knot_count", 10)?)
+}
+
+/// Answer for the parsed input
+pub fn compute(movements: &[Movement], params: &Params) -> Result<i32, SolveError> {
+    let mut rope = rope(movements, params)?;
+    rope.run();
+    Ok(rope.tail_visits() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn simple_movement() {
+        let input = "R 4";
+        let answer = Day09::solve_part_two(input);
+        assert_eq!(answer, Ok(1));
+    }
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![
+            (
+                "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2",
+                1,
+            ),
+            (
+                "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20",
+                36,
+            ),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(Day09::solve_part_two(input), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_common::generate::{Generate, Random, Rng, SliceRandom};
+use aoc_common::ocr;
+
+use crate::{Day10, CRT_HEIGHT};
+
+/// Width of the CRT the letters are drawn for.
+const WIDTH: usize = 40;
+
+impl Generate for Day10 {
+    /// A program drawing eight random letters on the CRT, whatever `size` is, since they fill it
+    fn generate(rng: &mut Random, _size: usize) -> String {
+        // X starts at 1, lighting the first two pixels, which only some letters begin with
+        let letters = ocr::letters().collect::<Vec<_>>();
+        let first = letters
+            .iter()
+            .filter(|letter| ocr::draw(&letter.to_string()).unwrap()[0].starts_with("##"))
+            .collect::<Vec<_>>();
+        let mut text = first.choose(rng).unwrap().to_string();
+        text.extend((1..WIDTH / (ocr::WIDTH + 1)).map(|_| letters.choose(rng).unwrap()));
+        let screen = ocr::draw(&text).unwrap();
+        let lit = |cycle: usize| screen[cycle / WIDTH].as_bytes()[cycle % WIDTH] == b'#';
+
+        // One `addx` per two cycles sets X for the next two pixels
+        let mut x = 1;
+        let mut input = String::new();
+        for cycle in (2..=WIDTH * CRT_HEIGHT).step_by(2) {
+            let next = if cycle == WIDTH * CRT_HEIGHT {
+                rng.gen_range(-5..=45)
+            } else {
+                sprite(rng, (cycle % WIDTH) as i32, lit(cycle), lit(cycle + 1))
+            };
+            match next - x {
+                0 => input.push_str("noop\nnoop\n"),
+                value => input.push_str(&format!("addx {}\n", value)),
+            }
+            x = next;
+        }
+        input
+    }
+}
+
+/// Position of the sprite lighting pixels `column` and the one after it, or not.
+fn sprite(rng: &mut Random, column: i32, left: bool, right: bool) -> i32 {
+    match (left, right) {
+        (true, true) => column + rng.gen_range(0..=1),
+        (true, false) => column - 1,
+        (false, true) => column + 2,
+        (false, false) => loop {
+            let x = rng.gen_range(-5..=45);
+            if !(column - 1..=column + 2).contains(&x) {
+                break x;
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day10::seeded(1, 20), Day10::seeded(1, 20));
+        assert_ne!(Day10::seeded(1, 20), Day10::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day10::seeded(seed, 20);
+            if let Err(err) = Day10::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+        let letters = Day10::solve_part_two(&Day10::seeded(3, 0)).unwrap();
+        assert_eq!(letters.len(), 8);
+    }
+}
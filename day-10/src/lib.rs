@@ -0,0 +1,247 @@
+use aoc_common::parse::lines;
+use aoc_common::simulation::{Draw, Frame};
+use aoc_common::{Params, Point, Simulation, Solution, SolveError};
+use parse_display::{Display, FromStr};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Cathode-Ray Tube
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Command>;
+    type PartOne = i32;
+    type PartTwo = String;
+    const PARAMS: &'static [&'static str] = &["crt_width"];
+
+    /// The program's instructions
+    fn parse(input: &str) -> Result<Vec<Command>, SolveError> {
+        lines(input).map(|line| line.parse::<Command>()).collect()
+    }
+
+    fn part_one(commands: &Vec<Command>) -> Result<i32, SolveError> {
+        part_1::compute(commands)
+    }
+
+    fn part_two(commands: &Vec<Command>) -> Result<String, SolveError> {
+        part_2::compute(commands, &Params::default())
+    }
+
+    fn part_two_with(commands: &Vec<Command>, params: &Params) -> Result<String, SolveError> {
+        part_2::compute(commands, params)
+    }
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    #[display("noop")]
+    Noop,
+    #[display("addx {0}")]
+    Add(i32),
+}
+
+/// Value of the X register during each cycle, followed by its value once the program has finished.
+fn register(commands: &[Command]) -> Vec<i32> {
+    let mut cpu = Cpu::new(commands);
+    let mut values = vec![cpu.x];
+    while cpu.step() {
+        values.push(cpu.x);
+    }
+    values
+}
+
+/// The CPU running a program, one cycle per step.
+#[derive(Debug, Clone)]
+pub struct Cpu<'a> {
+    commands: &'a [Command],
+    // Next command to start
+    next: usize,
+    // Value being added by an `addx` that is halfway through
+    adding: Option<i32>,
+    x: i32,
+    cycles: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(commands: &'a [Command]) -> Self {
+        Self {
+            commands,
+            next: 0,
+            adding: None,
+            x: 1,
+            cycles: 0,
+        }
+    }
+
+    /// Whether the program has finished.
+    pub fn halted(&self) -> bool {
+        self.adding.is_none() && self.next >= self.commands.len()
+    }
+}
+
+/// State of the CPU between two cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpuSnapshot {
+    /// Cycles completed
+    pub cycles: usize,
+    /// X register, as it is during the next cycle
+    pub x: i32,
+}
+
+impl Simulation for Cpu<'_> {
+    type Snapshot = CpuSnapshot;
+
+    /// Run one cycle
+    fn step(&mut self) -> bool {
+        if let Some(value) = self.adding.take() {
+            // Second cycle of an addx
+            self.x += value;
+        } else {
+            match self.commands.get(self.next) {
+                Some(Command::Noop) => {}
+                Some(Command::Add(value)) => self.adding = Some(*value),
+                None => return false,
+            }
+            self.next += 1;
+        }
+        self.cycles += 1;
+        true
+    }
+
+    fn steps(&self) -> usize {
+        self.cycles
+    }
+
+    fn snapshot(&self) -> CpuSnapshot {
+        CpuSnapshot {
+            cycles: self.cycles,
+            x: self.x,
+        }
+    }
+}
+
+/// Rows of the CRT
+const CRT_HEIGHT: usize = 6;
+
+/// The CRT drawn by the CPU, one pixel per cycle.
+#[derive(Debug, Clone)]
+pub struct Crt<'a> {
+    cpu: Cpu<'a>,
+    width: usize,
+    pixels: Vec<Vec<char>>,
+}
+
+impl<'a> Crt<'a> {
+    /// Blank CRT, `width` columns wide.
+    pub fn new(commands: &'a [Command], width: usize) -> Result<Self, SolveError> {
+        if width == 0 {
+            return Err(SolveError::param("crt_width must be at least 1"));
+        }
+        Ok(Self {
+            cpu: Cpu::new(commands),
+            width,
+            pixels: vec![vec![' '; width]; CRT_HEIGHT],
+        })
+    }
+
+    /// Whether the program has finished, rather than the beam running off the end of the screen.
+    pub fn halted(&self) -> bool {
+        self.cpu.halted()
+    }
+}
+
+/// The pixels drawn so far, and where the beam and sprite are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrtSnapshot {
+    pub rows: Vec<String>,
+    pub cpu: CpuSnapshot,
+    /// Column and row of the next pixel
+    pub beam: Point,
+}
+
+impl Simulation for Crt<'_> {
+    type Snapshot = CrtSnapshot;
+
+    /// Draw one pixel, lit if the sprite covers it
+    fn step(&mut self) -> bool {
+        let cycle = self.cpu.steps();
+        if cycle >= CRT_HEIGHT * self.width {
+            return false;
+        }
+        let sprite = self.cpu.x;
+        if !self.cpu.step() {
+            return false;
+        }
+        let (row, col) = (cycle / self.width, cycle % self.width);
+        let lit = ((sprite - 1)..=(sprite + 1)).contains(&(col as i32));
+        self.pixels[row][col] = if lit { '#' } else { '.' };
+        true
+    }
+
+    fn steps(&self) -> usize {
+        self.cpu.steps()
+    }
+
+    fn snapshot(&self) -> CrtSnapshot {
+        let cycle = self.cpu.steps();
+        CrtSnapshot {
+            rows: self.pixels.iter().map(String::from_iter).collect(),
+            cpu: self.cpu.snapshot(),
+            beam: Point::new((cycle % self.width) as i32, (cycle / self.width) as i32),
+        }
+    }
+}
+
+impl Draw for CrtSnapshot {
+    /// The screen, with the beam `@` and the sprite on a line below it
+    fn draw(&self) -> Frame {
+        let mut rows = self.rows.clone();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        if let Some(row) = rows.get_mut(self.beam.y as usize) {
+            *row = row
+                .chars()
+                .enumerate()
+                .map(|(col, c)| if col as i32 == self.beam.x { '@' } else { c })
+                .collect();
+        }
+        let sprite = (0..width as i32)
+            .map(|col| {
+                if (col - self.cpu.x).abs() <= 1 {
+                    '='
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        rows.push(String::new());
+        rows.push(sprite);
+
+        Frame {
+            rows,
+            origin: Point::ORIGIN,
+            focus: self.beam,
+            caption: format!("Cycle {}, X = {}", self.cpu.cycles + 1, self.cpu.x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use proptest::prelude::*;
+
+    fn command() -> impl Strategy<Value = Command> {
+        prop_oneof![Just(Command::Noop), any::<i32>().prop_map(Command::Add)]
+    }
+
+    proptest! {
+        #[test]
+        fn test_command_round_trip(command in command()) {
+            prop_assert_eq!(command.to_string().parse::<Command>(), Ok(command));
+        }
+    }
+}
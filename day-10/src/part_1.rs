@@ -0,0 +1,190 @@
+use aoc_common::SolveError;
+
+use crate::{register, Command};
+
+/// Answer for the parsed input
+pub fn compute(commands: &[Command]) -> Result<i32, SolveError> {
+    let important_signals = [20, 60, 100, 140, 180, 220];
+
+    let register = register(commands);
+    Ok(important_signals
+        .iter()
+        .filter_map(|cycle| Some(cycle * register.get(*cycle as usize - 1)?))
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cpu, Day10};
+    use aoc_common::{Simulation, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+            13140,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day10::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_cpu() {
+        let commands = Day10::parse("noop\naddx 3\naddx -5").unwrap();
+        let mut cpu = Cpu::new(&commands);
+        let x = cpu
+            .snapshots()
+            .map(|snapshot| snapshot.x)
+            .collect::<Vec<_>>();
+        assert_eq!(x, vec![1, 1, 1, 4, 4, -1]);
+        assert_eq!(cpu.steps(), 5);
+    }
+}
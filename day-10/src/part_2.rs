@@ -0,0 +1,228 @@
+use aoc_common::{ocr, Params, Simulation, SolveError};
+
+use crate::{Command, Crt};
+
+/// Columns of the CRT
+const CRT_WIDTH: usize = 40;
+
+/// The CRT, 40 columns wide unless `crt_width` is set
+pub fn crt<'a>(commands: &'a [Command], params: &Params) -> Result<Crt<'a>, SolveError> {
+    Crt::new(commands, params.get("crt_width", CRT_WIDTH)?)
+}
+
+/// Answer for the parsed input
+pub fn compute(commands: &[Command], params: &Params) -> Result<String, SolveError> {
+    let screen = screen(crt(commands, params)?)?;
+    ocr::read(&screen).map_err(|err| {
+        SolveError::no_solution(format!("{}, on the screen:\n{}", err, screen.join("\n")))
+    })
+}
+
+/// What the program draws on the CRT, row by row
+pub fn screen(mut crt: Crt) -> Result<Vec<String>, SolveError> {
+    crt.run();
+    if !crt.halted() {
+        return Err(SolveError::no_solution(
+            "Program runs past the end of the screen",
+        ));
+    }
+    Ok(crt.snapshot().rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....",
+        )];
+        for (input, expected) in tests {
+            let commands = Day10::parse(input).unwrap();
+            assert_eq!(
+                screen(Crt::new(&commands, CRT_WIDTH).unwrap())
+                    .unwrap()
+                    .join("\n"),
+                expected
+            );
+            assert!(matches!(
+                Day10::solve_part_two(input),
+                Err(SolveError::NoSolution(message))
+                    if message.starts_with("Unrecognised letters at columns 1-4, 6-9,")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_crt_width() {
+        let mut params = Params::default();
+        params.set("crt_width=20").unwrap();
+        assert_eq!(
+            Day10::solve_part_two_with(include_str!("../example.txt"), &params),
+            Err(SolveError::no_solution(
+                "Program runs past the end of the screen"
+            ))
+        );
+        params.set("crt_width=0").unwrap();
+        assert!(matches!(
+            Day10::solve_part_two_with(include_str!("../example.txt"), &params),
+            Err(SolveError::Param(_))
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_common::generate::{Generate, Random, Rng, SliceRandom};
+
+use crate::Day11;
+
+/// Divisors the monkeys test by, whose product keeps part two's worry levels small.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// A monkey as written in the notes.
+struct Monkey {
+    items: Vec<u64>,
+    // Operator, and the number on its right, or `None` for `old`
+    operation: (char, Option<u64>),
+    divisible_by: u64,
+    throw: [usize; 2],
+}
+
+impl Generate for Day11 {
+    /// Notes on four to eight monkeys holding `size` items between them, at least one each
+    fn generate(rng: &mut Random, size: usize) -> String {
+        // Some notes have worry levels too large for part one after 20 rounds, so try again
+        loop {
+            let monkeys = monkeys(rng, size.max(1));
+            if fits(&monkeys) {
+                return write(&monkeys);
+            }
+        }
+    }
+}
+
+fn monkeys(rng: &mut Random, items: usize) -> Vec<Monkey> {
+    let count = rng.gen_range(4..=8);
+    let mut divisors = PRIMES;
+    divisors.shuffle(rng);
+    let mut monkeys = (0..count)
+        .map(|index| {
+            let operation = match rng.gen_range(0..10) {
+                0 => ('*', None),
+                1..=4 => ('*', Some(rng.gen_range(2..=19))),
+                _ => ('+', Some(rng.gen_range(1..=8))),
+            };
+            // Any other monkey
+            let mut target = || (index + rng.gen_range(1..count)) % count;
+            Monkey {
+                items: Vec::new(),
+                operation,
+                divisible_by: divisors[index],
+                throw: [target(), target()],
+            }
+        })
+        .collect::<Vec<_>>();
+    // At least one each, since a list of starting items cannot be empty
+    for index in 0..items.max(count) {
+        let monkey = if index < count {
+            index
+        } else {
+            rng.gen_range(0..count)
+        };
+        monkeys[monkey].items.push(rng.gen_range(40..=99));
+    }
+    monkeys
+}
+
+/// Whether 20 rounds of part one keep every worry level within a `u64`.
+fn fits(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<_>>();
+    for _ in 0..20 {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            for old in std::mem::take(&mut items[index]) {
+                let right = monkey.operation.1.unwrap_or(old);
+                let new = match monkey.operation.0 {
+                    '*' => old.checked_mul(right),
+                    _ => old.checked_add(right),
+                };
+                let Some(worry) = new.map(|new| new / 3) else {
+                    return false;
+                };
+                let target = monkey.throw[usize::from(!worry.is_multiple_of(monkey.divisible_by))];
+                items[target].push(worry);
+            }
+        }
+    }
+    true
+}
+
+fn write(monkeys: &[Monkey]) -> String {
+    let notes = monkeys
+        .iter()
+        .enumerate()
+        .map(|(index, monkey)| {
+            let items = monkey
+                .items
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>();
+            let right = monkey
+                .operation
+                .1
+                .map_or("old".to_string(), |n| n.to_string());
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                index,
+                items.join(", "),
+                monkey.operation.0,
+                right,
+                monkey.divisible_by,
+                monkey.throw[0],
+                monkey.throw[1]
+            )
+        })
+        .collect::<Vec<_>>();
+    notes.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day11::seeded(1, 20), Day11::seeded(1, 20));
+        assert_ne!(Day11::seeded(1, 20), Day11::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day11::seeded(seed, 20);
+            if let Err(err) = Day11::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
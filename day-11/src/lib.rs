@@ -0,0 +1,467 @@
+use aoc_common::parse::{paragraphs, Line};
+use aoc_common::{Params, Simulation, Solution, SolveError};
+use parse_display::{Display, FromStr};
+use std::fmt;
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Monkey in the Middle
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+    const PARAMS: &'static [&'static str] = &["number_of_rounds"];
+
+    /// Every monkey, in order
+    fn parse(input: &str) -> Result<Vec<Monkey>, SolveError> {
+        let paragraphs = paragraphs(input);
+        let monkeys = paragraphs
+            .iter()
+            .map(|lines| Monkey::parse(lines))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Monkeys are numbered in order, and only throw to each other
+        for (position, (monkey, lines)) in monkeys.iter().zip(&paragraphs).enumerate() {
+            if monkey.index != position {
+                return Err(lines[0].error(lines[0].text, format!("Expected Monkey {}", position)));
+            }
+            for (n, target) in [(4, monkey.throw_true), (5, monkey.throw_false)] {
+                if target >= monkeys.len() {
+                    return Err(
+                        lines[n].error(lines[n].text.trim(), format!("No monkey {}", target))
+                    );
+                }
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> Result<u64, SolveError> {
+        part_1::compute(monkeys, &Params::default())
+    }
+
+    fn part_one_with(monkeys: &Vec<Monkey>, params: &Params) -> Result<u64, SolveError> {
+        part_1::compute(monkeys, params)
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> Result<u64, SolveError> {
+        part_2::compute(monkeys, &Params::default())
+    }
+
+    fn part_two_with(monkeys: &Vec<Monkey>, params: &Params) -> Result<u64, SolveError> {
+        part_2::compute(monkeys, params)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
+    index: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    test: Test,
+    throw_true: usize,
+    throw_false: usize,
+    times_inspected: u64,
+}
+
+impl Monkey {
+    /// Monkey from its paragraph of six lines
+    fn parse(lines: &[Line]) -> Result<Self, SolveError> {
+        let line = |n: usize| {
+            lines.get(n).ok_or_else(|| {
+                let last = lines[lines.len() - 1];
+                last.error(&last.text[last.text.len()..], "Expected 6 lines per monkey")
+            })
+        };
+        let monkey = line(0)?.parse::<MonkeyIndex>()?;
+        let items = line(1)?.parse::<Items>()?;
+        let operation = line(2)?.parse::<Operation>()?;
+        let test = line(3)?.parse::<Test>()?;
+        if test.divisible_by == 0 {
+            return Err(line(3)?.error(line(3)?.text.trim(), "Cannot test for divisibility by 0"));
+        }
+        let throw_true = line(4)?.parse::<ThrowTrue>()?;
+        let throw_false = line(5)?.parse::<ThrowFalse>()?;
+
+        Ok(Monkey {
+            index: monkey.0,
+            items: items.0,
+            operation,
+            test,
+            throw_true: throw_true.monkey,
+            throw_false: throw_false.monkey,
+            times_inspected: 0,
+        })
+    }
+}
+
+/// The monkey's paragraph of notes, without a trailing newline
+impl fmt::Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", MonkeyIndex(self.index))?;
+        writeln!(f, "  {}", Items(self.items.clone()))?;
+        writeln!(f, "  {}", self.operation)?;
+        writeln!(f, "  {}", self.test)?;
+        writeln!(
+            f,
+            "    {}",
+            ThrowTrue {
+                monkey: self.throw_true
+            }
+        )?;
+        write!(
+            f,
+            "    {}",
+            ThrowFalse {
+                monkey: self.throw_false
+            }
+        )
+    }
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+#[display("Monkey {0}:")]
+struct MonkeyIndex(usize);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Operation {
+    left: Operand,
+    right: Operand,
+    operator: Operator,
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+enum Operator {
+    #[display("+")]
+    Add,
+    #[display("*")]
+    Multiply,
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    #[display("old")]
+    Old,
+    #[display("{0}")]
+    Number(u64),
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Operation: new = {} {} {}",
+            self.left, self.operator, self.right
+        )
+    }
+}
+
+impl std::str::FromStr for Operation {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, expression) = s.split_once('=').ok_or(())?;
+        let mut parts = expression.split_whitespace();
+        let mut next = || parts.next().ok_or(());
+        let left = next()?.parse().map_err(|_| ())?;
+        let operator = next()?.parse().map_err(|_| ())?;
+        let right = next()?.parse().map_err(|_| ())?;
+        if parts.next().is_some() {
+            return Err(());
+        }
+        Ok(Operation {
+            left,
+            right,
+            operator,
+        })
+    }
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it overflows u64
+    fn apply(&self, old: u64) -> Option<u64> {
+        let value = |operand: &Operand| match operand {
+            Operand::Old => old,
+            Operand::Number(n) => *n,
+        };
+        let (left, right) = (value(&self.left), value(&self.right));
+        match self.operator {
+            Operator::Add => left.checked_add(right),
+            Operator::Multiply => left.checked_mul(right),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Items(Vec<u64>);
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+struct Test {
+    #[display("Test: divisible by {}")]
+    divisible_by: usize,
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+struct ThrowTrue {
+    #[display("If true: throw to monkey {}")]
+    monkey: usize,
+}
+
+#[derive(Display, FromStr, Debug, Clone, PartialEq, Eq)]
+struct ThrowFalse {
+    #[display("If false: throw to monkey {}")]
+    monkey: usize,
+}
+
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = self.0.iter().map(u64::to_string).collect::<Vec<_>>();
+        if items.is_empty() {
+            write!(f, "Starting items:")
+        } else {
+            write!(f, "Starting items: {}", items.join(", "))
+        }
+    }
+}
+
+impl std::str::FromStr for Items {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = s.strip_prefix("Starting items:").ok_or(())?.trim();
+        if items.is_empty() {
+            // A monkey holding nothing yet
+            return Ok(Self(Vec::new()));
+        }
+        let items = items
+            .split(',')
+            .map(|s| s.trim().parse().map_err(|_| ()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self(items))
+    }
+}
+
+/// Product of the two highest numbers of items inspected by a monkey, after some rounds.
+fn monkey_business(
+    monkeys: &[Monkey],
+    number_of_rounds: usize,
+    relief: Relief,
+) -> Result<u64, SolveError> {
+    let mut troop = Troop::new(monkeys, relief);
+    troop.run_until(|troop| troop.steps() == number_of_rounds);
+    if let Some(error) = troop.error {
+        return Err(error);
+    }
+
+    // Get 2 highest times inspected
+    let mut times_inspected = troop.snapshot().inspected;
+    times_inspected.sort_by_key(|inspected| std::cmp::Reverse(*inspected));
+    match times_inspected[..] {
+        [first, second, ..] => Ok(first * second),
+        _ => Err(SolveError::no_solution("Fewer than two monkeys")),
+    }
+}
+
+/// How worry levels are lowered after each inspection.
+#[derive(Debug, Clone, Copy)]
+pub enum Relief {
+    /// Divided, rounding down
+    Divide(u64),
+    /// Wrapped around, which keeps every divisibility test the same if it is a multiple of their divisors
+    Modulo(u64),
+}
+
+impl Relief {
+    fn apply(self, worry: u64) -> u64 {
+        match self {
+            Relief::Divide(n) => worry / n,
+            Relief::Modulo(n) => worry % n,
+        }
+    }
+}
+
+/// Monkeys throwing items to each other, one round per step.
+#[derive(Debug, Clone)]
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    relief: Relief,
+    rounds: usize,
+    /// Why the troop stopped partway through a round, if it did
+    error: Option<SolveError>,
+}
+
+impl Troop {
+    pub fn new(monkeys: &[Monkey], relief: Relief) -> Self {
+        Self {
+            monkeys: monkeys.to_vec(),
+            relief,
+            rounds: 0,
+            error: None,
+        }
+    }
+}
+
+/// Items held by each monkey between rounds, and how many each has inspected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TroopSnapshot {
+    pub rounds: usize,
+    pub items: Vec<Vec<u64>>,
+    pub inspected: Vec<u64>,
+}
+
+impl Simulation for Troop {
+    type Snapshot = TroopSnapshot;
+
+    /// Play a round, in which each monkey in turn inspects and throws all of its items
+    ///
+    /// A worry level overflowing u64 stops the troop for good, with the error kept.
+    fn step(&mut self) -> bool {
+        if self.error.is_some() {
+            return false;
+        }
+        for index in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[index].items);
+            self.monkeys[index].times_inspected += items.len() as u64;
+            for item in items {
+                let monkey = &self.monkeys[index];
+                let Some(worry) = monkey.operation.apply(item) else {
+                    self.error = Some(SolveError::no_solution(format!(
+                        "Monkey {} overflows u64 inspecting {} with {}",
+                        index, item, monkey.operation
+                    )));
+                    return false;
+                };
+                let worry = self.relief.apply(worry);
+                let target = if worry.is_multiple_of(monkey.test.divisible_by as u64) {
+                    monkey.throw_true
+                } else {
+                    monkey.throw_false
+                };
+                self.monkeys[target].items.push(worry);
+            }
+        }
+        self.rounds += 1;
+        true
+    }
+
+    fn steps(&self) -> usize {
+        self.rounds
+    }
+
+    fn snapshot(&self) -> TroopSnapshot {
+        TroopSnapshot {
+            rounds: self.rounds,
+            items: self.monkeys.iter().map(|m| m.items.clone()).collect(),
+            inspected: self.monkeys.iter().map(|m| m.times_inspected).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use proptest::prelude::*;
+
+    fn operator() -> impl Strategy<Value = Operator> {
+        prop_oneof![Just(Operator::Add), Just(Operator::Multiply)]
+    }
+
+    fn operand() -> impl Strategy<Value = Operand> {
+        prop_oneof![Just(Operand::Old), any::<u64>().prop_map(Operand::Number)]
+    }
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        (operand(), operator(), operand()).prop_map(|(left, operator, right)| Operation {
+            left,
+            right,
+            operator,
+        })
+    }
+
+    /// Notes on `count` monkeys, numbered in order and throwing only to each other
+    fn monkeys(count: usize) -> impl Strategy<Value = Vec<Monkey>> {
+        let monkey = (
+            prop::collection::vec(any::<u64>(), 0..5),
+            operation(),
+            1..=usize::MAX,
+            0..count,
+            0..count,
+        );
+        prop::collection::vec(monkey, count).prop_map(|monkeys| {
+            monkeys
+                .into_iter()
+                .enumerate()
+                .map(
+                    |(index, (items, operation, divisible_by, throw_true, throw_false))| Monkey {
+                        index,
+                        items,
+                        operation,
+                        test: Test { divisible_by },
+                        throw_true,
+                        throw_false,
+                        times_inspected: 0,
+                    },
+                )
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_monkey_index_round_trip(index: usize) {
+            let index = MonkeyIndex(index);
+            prop_assert_eq!(index.to_string().parse::<MonkeyIndex>(), Ok(index));
+        }
+
+        #[test]
+        fn test_operator_round_trip(operator in operator()) {
+            prop_assert_eq!(operator.to_string().parse::<Operator>(), Ok(operator));
+        }
+
+        #[test]
+        fn test_operand_round_trip(operand in operand()) {
+            prop_assert_eq!(operand.to_string().parse::<Operand>(), Ok(operand));
+        }
+
+        #[test]
+        fn test_operation_round_trip(operation in operation()) {
+            prop_assert_eq!(operation.to_string().parse::<Operation>(), Ok(operation));
+        }
+
+        #[test]
+        fn test_items_round_trip(items in prop::collection::vec(any::<u64>(), 0..10)) {
+            let items = Items(items);
+            prop_assert_eq!(items.to_string().parse::<Items>(), Ok(items));
+        }
+
+        #[test]
+        fn test_test_round_trip(divisible_by: usize) {
+            let test = Test { divisible_by };
+            prop_assert_eq!(test.to_string().parse::<Test>(), Ok(test));
+        }
+
+        #[test]
+        fn test_throw_round_trip(monkey: usize) {
+            let throw_true = ThrowTrue { monkey };
+            prop_assert_eq!(throw_true.to_string().parse::<ThrowTrue>(), Ok(throw_true));
+            let throw_false = ThrowFalse { monkey };
+            prop_assert_eq!(throw_false.to_string().parse::<ThrowFalse>(), Ok(throw_false));
+        }
+
+        #[test]
+        fn test_notes_round_trip(monkeys in (1..8usize).prop_flat_map(monkeys)) {
+            let notes = monkeys
+                .iter()
+                .map(Monkey::to_string)
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            prop_assert_eq!(Day11::parse(&notes), Ok(monkeys));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_common::{Params, SolveError};
+
+use crate::{monkey_business, Monkey, Relief};
+
+/// Answer for the parsed input
+pub fn compute(monkeys: &[Monkey], params: &Params) -> Result<u64, SolveError> {
+    // Config
+    let number_of_rounds = params.get("number_of_rounds", 20)?;
+
+    // Worry level is reducded, divided by 3
+    monkey_business(monkeys, number_of_rounds, Relief::Divide(3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day11, Relief, Troop};
+    use aoc_common::{Simulation, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+            10605,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day11::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54, x
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0";
+        assert_eq!(
+            Day11::solve_part_one(input),
+            Err(
+                SolveError::parse(9, 3, "Invalid Items: <Starting items: 54, x>")
+                    .with_text("  Starting items: 54, x")
+            )
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "Monkey 0:
+  Starting items: 18446744073709551615
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items:
+  Operation: new = old + 1
+  Test: divisible by 2
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        assert_eq!(
+            Day11::solve_part_one(input),
+            Err(SolveError::no_solution(
+                "Monkey 0 overflows u64 inspecting 18446744073709551615 with Operation: new = old * old"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_troop() {
+        let monkeys = Day11::parse(include_str!("../example.txt")).unwrap();
+        let mut troop = Troop::new(&monkeys, Relief::Divide(3));
+        troop.step();
+        assert_eq!(
+            troop.snapshot().items,
+            vec![
+                vec![20, 23, 27, 26],
+                vec![2080, 25, 167, 207, 401, 1046],
+                vec![],
+                vec![],
+            ]
+        );
+        troop.run_until(|troop| troop.steps() == 20);
+        assert_eq!(troop.snapshot().inspected, vec![101, 95, 7, 105]);
+    }
+}
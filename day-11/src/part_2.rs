@@ -0,0 +1,75 @@
+use aoc_common::{Params, SolveError};
+
+use crate::{monkey_business, Monkey, Relief};
+
+/// Answer for the parsed input
+pub fn compute(monkeys: &[Monkey], params: &Params) -> Result<u64, SolveError> {
+    // Config
+    let number_of_rounds = params.get("number_of_rounds", 10_000)?;
+
+    // Chinese Remainder Theorem
+    //
+    // Credit: https://www.youtube.com/watch?v=0RkTrYDyzmE
+    let magic_trick = monkeys
+        .iter()
+        .map(|m| m.test.divisible_by as u64)
+        .product::<u64>();
+
+    // Manage Worry Level
+    monkey_business(monkeys, number_of_rounds, Relief::Modulo(magic_trick))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use aoc_common::{Params, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+            2713310158,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day11::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_number_of_rounds() {
+        let mut params = Params::default();
+        params.set("number_of_rounds=20").unwrap();
+        assert_eq!(
+            Day11::solve_part_two_with(include_str!("../example.txt"), &params),
+            Ok(103 * 99)
+        );
+    }
+}
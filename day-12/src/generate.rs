@@ -0,0 +1,69 @@
+use aoc_common::generate::{Generate, Random, Rng};
+use aoc_common::{Grid, Point};
+
+use crate::Day12;
+
+/// Elevations from `a` to `z`.
+const HEIGHTS: usize = 26;
+
+impl Generate for Day12 {
+    /// A heightmap `size` squares wide, at least 26, with a climbable path from `S` on the left to `E`
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let width = size.max(HEIGHTS);
+        let height = (width / 3).max(5);
+        let mut map = Grid::new(width, height, 'a');
+        for point in map.points().collect::<Vec<_>>() {
+            map[point] = rng.gen_range(b'a'..=b'z') as char;
+        }
+
+        // Wander right, and up or down, without crossing itself, until the right edge
+        let mut point = Point::new(0, rng.gen_range(0..height) as i32);
+        let mut path = vec![point];
+        let mut vertical = Point::ORIGIN;
+        while point.x < width as i32 - 1 {
+            let step = match rng.gen_range(0..3) {
+                0 if vertical != Point::DOWN && point.y > 0 => Point::UP,
+                1 if vertical != Point::UP && point.y < height as i32 - 1 => Point::DOWN,
+                _ => Point::RIGHT,
+            };
+            vertical = if step == Point::RIGHT {
+                Point::ORIGIN
+            } else {
+                step
+            };
+            point += step;
+            path.push(point);
+        }
+
+        // Climbing at most one elevation per step, from the bottom to the top
+        for (index, point) in path.iter().enumerate() {
+            let elevation = index * (HEIGHTS - 1) / (path.len() - 1);
+            map[*point] = (b'a' + elevation as u8) as char;
+        }
+        map[path[0]] = 'S';
+        map[path[path.len() - 1]] = 'E';
+        format!("{}\n", map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day12::seeded(1, 20), Day12::seeded(1, 20));
+        assert_ne!(Day12::seeded(1, 20), Day12::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day12::seeded(seed, 20);
+            if let Err(err) = Day12::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+        assert!(Day12::solve_part_one(&Day12::seeded(3, 40)).unwrap() >= 25);
+    }
+}
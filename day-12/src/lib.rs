@@ -0,0 +1,245 @@
+use std::fmt;
+
+use aoc_common::picture::{Palette, Rgb};
+use aoc_common::{Grid, Point, Simulation, Solution, SolveError};
+use petgraph::prelude::DiGraphMap;
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Hill Climbing Algorithm
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Grid<Node>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// The heightmap
+    fn parse(input: &str) -> Result<Grid<Node>, SolveError> {
+        Ok(Grid::parse(input, |c| match c {
+            'S' => Some(Node::Start),
+            'E' => Some(Node::End),
+            'a'..='z' => Some(Node::Height(c.to_num())),
+            _ => c.to_digit(10).map(|h| Node::Height(h as i32)),
+        })?)
+    }
+
+    fn part_one(board: &Grid<Node>) -> Result<i32, SolveError> {
+        part_1::compute(board)
+    }
+
+    fn part_two(board: &Grid<Node>) -> Result<i32, SolveError> {
+        part_2::compute(board)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Node {
+    Start,
+    End,
+    Height(i32),
+}
+
+// Trait for chars to turn into numbers
+// a -> 1, b -> 2, etc
+trait CharToNum {
+    fn to_num(&self) -> i32;
+}
+
+impl CharToNum for char {
+    fn to_num(&self) -> i32 {
+        let mut num = *self as i32 - 96;
+        if num < 0 {
+            num = 0;
+        }
+        num
+    }
+}
+
+impl Node {
+    pub fn height(&self) -> i32 {
+        match self {
+            Node::Start => 'a'.to_num(),
+            Node::End => 'z'.to_num(),
+            Node::Height(h) => *h,
+        }
+    }
+}
+
+impl Node {
+    pub fn char(&self) -> char {
+        match self {
+            Node::Start => 'S',
+            Node::End => 'E',
+            Node::Height(h) => (b'a' + (*h as u8 - 1)) as char,
+        }
+    }
+}
+
+impl Node {
+    pub fn can_move_to(&self, other: &Node) -> bool {
+        // Other can be 1 unit higher, or any number of units lower
+        let diff = self.height() - other.height();
+        diff >= -1
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.char())
+    }
+}
+
+/// Every step that can be taken on the heightmap.
+fn graph(board: &Grid<Node>) -> DiGraphMap<Point, ()> {
+    let mut edges: Vec<(Point, Point)> = Vec::new();
+    for (point, node) in board.iter() {
+        // Up, Down, Left, Right
+        for (neighbor, other) in board.neighbors4(point) {
+            if node.can_move_to(other) {
+                edges.push((point, neighbor));
+            }
+        }
+    }
+
+    DiGraphMap::from_edges(&edges)
+}
+
+/// Breadth-first search from the starts towards the end, reaching one more step out each step.
+#[derive(Debug, Clone)]
+pub struct Search<'a> {
+    board: &'a Grid<Node>,
+    // Where each point reached was reached from, with the starts reached from themselves
+    parents: Grid<Option<Point>>,
+    frontier: Vec<Point>,
+    end: Option<Point>,
+    steps: usize,
+}
+
+impl<'a> Search<'a> {
+    pub fn new(board: &'a Grid<Node>, starts: impl IntoIterator<Item = Point>) -> Self {
+        let mut parents = board.map(|_| None);
+        let frontier = starts.into_iter().collect::<Vec<_>>();
+        for start in &frontier {
+            parents[*start] = Some(*start);
+        }
+        Self {
+            board,
+            parents,
+            frontier,
+            end: None,
+            steps: 0,
+        }
+    }
+
+    /// Fewest steps from a start to the end, once it has been reached.
+    pub fn distance(&self) -> Option<usize> {
+        self.end.map(|_| self.steps)
+    }
+
+    /// Shortest path from a start to the end, once it has been reached.
+    pub fn path(&self) -> Vec<Point> {
+        let mut path = Vec::new();
+        let mut point = self.end;
+        while let Some(current) = point {
+            path.push(current);
+            point = self.parents[current].filter(|parent| *parent != current);
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl Simulation for Search<'_> {
+    type Snapshot = Grid<Spot>;
+
+    /// Reach every point one step beyond the frontier, stopping at the end
+    fn step(&mut self) -> bool {
+        if self.end.is_some() || self.frontier.is_empty() {
+            return false;
+        }
+        let mut next = Vec::new();
+        for point in std::mem::take(&mut self.frontier) {
+            let node = self.board[point];
+            for (neighbor, other) in self.board.neighbors4(point) {
+                if self.parents[neighbor].is_none() && node.can_move_to(other) {
+                    self.parents[neighbor] = Some(point);
+                    if *other == Node::End {
+                        self.end = Some(neighbor);
+                    }
+                    next.push(neighbor);
+                }
+            }
+        }
+        self.frontier = next;
+        self.steps += 1;
+        true
+    }
+
+    fn steps(&self) -> usize {
+        self.steps
+    }
+
+    fn snapshot(&self) -> Grid<Spot> {
+        let mut spots = self.board.map(|node| Spot::Ground(node.height()));
+        for (point, parent) in self.parents.iter() {
+            if parent.is_some() {
+                spots[point] = Spot::Reached(self.board[point].height());
+            }
+        }
+        for point in self.frontier.iter().chain(&self.path()) {
+            spots[*point] = Spot::Path;
+        }
+        for (point, node) in self.board.iter() {
+            match node {
+                Node::Start => spots[point] = Spot::Start,
+                Node::End => spots[point] = Spot::End,
+                Node::Height(_) => {}
+            }
+        }
+        spots
+    }
+}
+
+/// A point of the heightmap, and whether the search has reached it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spot {
+    Ground(i32),
+    Reached(i32),
+    /// On the frontier of the search, or the path found
+    Path,
+    Start,
+    End,
+}
+
+/// Shades of green for the ground and blue for reached points, higher being lighter, then red, white and yellow
+const fn shades() -> [Rgb; 55] {
+    let mut colours = [[0; 3]; 55];
+    let mut height = 0;
+    while height < 26 {
+        let light = 40 + 8 * height as u8;
+        colours[height] = [light / 2, light, light / 2];
+        colours[26 + height] = [light / 3, light / 2, light];
+        height += 1;
+    }
+    colours[52] = [230, 50, 40];
+    colours[53] = [255, 255, 255];
+    colours[54] = [255, 220, 40];
+    colours
+}
+
+impl Palette for Spot {
+    const COLOURS: &'static [Rgb] = &shades();
+
+    fn colour(&self) -> u8 {
+        match self {
+            Spot::Ground(height) => (*height - 1).clamp(0, 25) as u8,
+            Spot::Reached(height) => 26 + (*height - 1).clamp(0, 25) as u8,
+            Spot::Path => 52,
+            Spot::Start => 53,
+            Spot::End => 54,
+        }
+    }
+}
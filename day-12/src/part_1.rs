@@ -0,0 +1,74 @@
+use aoc_common::{Grid, Point, SolveError};
+use petgraph::algo::dijkstra;
+
+use crate::{graph, Node, Search};
+
+/// Search from the start <S>
+pub fn search(board: &Grid<Node>) -> Result<Search<'_>, SolveError> {
+    let start = start(board)?;
+    Ok(Search::new(board, [start]))
+}
+
+fn start(board: &Grid<Node>) -> Result<Point, SolveError> {
+    board
+        .position(|node| *node == Node::Start)
+        .ok_or_else(|| SolveError::no_solution("No start <S> on the map"))
+}
+
+/// Answer for the parsed input
+pub fn compute(board: &Grid<Node>) -> Result<i32, SolveError> {
+    let start = start(board)?;
+    let end = board
+        .position(|node| *node == Node::End)
+        .ok_or_else(|| SolveError::no_solution("No end <E> on the map"))?;
+
+    let graph = graph(board);
+
+    let result = dijkstra(&graph, start, Some(end), |_| 1);
+    result
+        .get(&end)
+        .copied()
+        .ok_or_else(|| SolveError::no_solution("The end cannot be reached"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day12, Spot};
+    use aoc_common::picture::Paint;
+    use aoc_common::{Point, Simulation, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+            31,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day12::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let board = Day12::parse(include_str!("../example.txt")).unwrap();
+        let mut search = super::search(&board).unwrap();
+        search.run_until(|search| search.steps() == 2);
+        let spots = search.snapshot();
+        assert_eq!(spots[Point::new(0, 0)], Spot::Start);
+        assert_eq!(spots[Point::new(1, 0)], Spot::Reached(1));
+        assert_eq!(spots[Point::new(2, 0)], Spot::Path);
+        assert_eq!(spots[Point::new(3, 0)], Spot::Ground(17));
+        assert_eq!(&spots.paint().pixels()[..4], &[53, 26, 52, 16]);
+
+        assert_eq!(search.run(), 29);
+        assert_eq!(search.distance(), Some(31));
+        assert_eq!(search.path().len(), 32);
+        assert_eq!(search.path().last(), Some(&Point::new(5, 2)));
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{Grid, SolveError};
+use petgraph::algo::dijkstra;
+
+use crate::{graph, CharToNum, Node, Search};
+
+/// Search from every square of elevation <a>
+pub fn search(board: &Grid<Node>) -> Search<'_> {
+    let starts = board
+        .iter()
+        .filter(|(_, node)| node.height() == 'a'.to_num())
+        .map(|(point, _)| point);
+    Search::new(board, starts)
+}
+
+/// Answer for the parsed input
+pub fn compute(board: &Grid<Node>) -> Result<i32, SolveError> {
+    let end = board
+        .position(|node| *node == Node::End)
+        .ok_or_else(|| SolveError::no_solution("No end <E> on the map"))?;
+
+    let graph = graph(board);
+
+    // find all 'a' or 'S' in nodes
+    let start_nodes = board
+        .iter()
+        .filter(|(_, node)| node.height() == 'a'.to_num())
+        .map(|(point, _)| point);
+
+    let mut results: Vec<i32> = Vec::new();
+    for start_node in start_nodes {
+        let res = dijkstra(&graph, start_node, Some(end), |_| 1);
+        if let Some(result) = res.get(&end) {
+            results.push(*result);
+        }
+    }
+
+    results
+        .into_iter()
+        .min()
+        .ok_or_else(|| SolveError::no_solution("The end cannot be reached"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+    use aoc_common::{Simulation, Solution};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+            29,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day12::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let board = Day12::parse(include_str!("../example.txt")).unwrap();
+        let mut search = super::search(&board);
+        assert_eq!(search.run(), 29);
+        assert_eq!(search.distance(), Some(29));
+    }
+}
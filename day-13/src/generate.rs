@@ -0,0 +1,73 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::Day13;
+
+impl Generate for Day13 {
+    /// `size` pairs of packets, nested up to four lists deep, none sorting level with a divider
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let mut next = || loop {
+            let packet = packet(rng, 0);
+            if !is_divider(&packet) {
+                return packet;
+            }
+        };
+        let pairs = (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", next(), next()))
+            .collect::<Vec<_>>();
+        pairs.join("\n")
+    }
+}
+
+/// Whether a packet is only 2 or 6 wrapped in lists, which compares equal to the divider packets.
+fn is_divider(packet: &str) -> bool {
+    matches!(packet.trim_matches(['[', ']']), "2" | "6")
+}
+
+/// A list of integers and lists, `depth` lists deep.
+fn packet(rng: &mut Random, depth: usize) -> String {
+    let elements = (0..rng.gen_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.gen_bool(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.gen_range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use super::is_divider;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day13::seeded(1, 20), Day13::seeded(1, 20));
+        assert_ne!(Day13::seeded(1, 20), Day13::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day13::seeded(seed, 20);
+            if let Err(err) = Day13::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_size() {
+        // The runner's default size, where packets like [[2]] turned up in every seed
+        for seed in 0..20 {
+            let input = Day13::seeded(seed, 100);
+            assert!(!input.lines().any(is_divider), "Seed {}", seed);
+            assert!(Day13::solve(&input).is_ok(), "Seed {}", seed);
+        }
+        assert!(is_divider("[[[6]]]"));
+        assert!(!is_divider("[[2],[]]"));
+    }
+}
@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use aoc_common::parse::{paragraphs, Line};
+use aoc_common::{Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Distress Signal
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<(Element, Element)>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    /// Every pair of packets
+    fn parse(input: &str) -> Result<Vec<(Element, Element)>, SolveError> {
+        paragraphs(input)
+            .iter()
+            .map(|lines| match lines[..] {
+                [left, right] => Ok((packet(&left)?, packet(&right)?)),
+                [only] => {
+                    Err(only.error(&only.text[only.text.len()..], "Expected a second packet"))
+                }
+                _ => Err(lines[2].error(lines[2].text, "Expected a blank line between pairs")),
+            })
+            .collect()
+    }
+
+    fn part_one(pairs: &Vec<(Element, Element)>) -> Result<i32, SolveError> {
+        part_1::compute(pairs)
+    }
+
+    fn part_two(pairs: &Vec<(Element, Element)>) -> Result<i32, SolveError> {
+        part_2::compute(pairs)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Element {
+    Vector(Vec<Element>),
+    Number(i32),
+}
+
+impl FromStr for Element {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str::<serde_json::Value>(s)
+            .map_err(|_| ())?
+            .try_into()
+    }
+}
+
+impl From<i32> for Element {
+    fn from(val: i32) -> Self {
+        Element::Number(val)
+    }
+}
+
+impl From<&i32> for Element {
+    fn from(val: &i32) -> Self {
+        Element::Number(*val)
+    }
+}
+
+impl TryFrom<serde_json::Value> for Element {
+    type Error = ();
+
+    fn try_from(val: serde_json::Value) -> Result<Self, Self::Error> {
+        match val {
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .and_then(|n| i32::try_from(n).ok())
+                .map(Element::Number)
+                .ok_or(()),
+            serde_json::Value::Array(a) => Ok(Element::Vector(
+                a.into_iter()
+                    .map(Element::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Packet on a line, pointing at where it stops being valid
+fn packet(line: &Line) -> Result<Element, SolveError> {
+    let value = serde_json::from_str::<serde_json::Value>(line.text).map_err(|err| {
+        SolveError::parse(
+            line.number(),
+            err.column().max(1),
+            "Expected a packet, like <[1,[2]]>",
+        )
+        .with_text(line.text)
+    })?;
+    Element::try_from(value)
+        .map_err(|_| line.error(line.text, "Packets hold only lists and integers"))
+}
+
+fn compare(left: &Element, right: &Element) -> Ordering {
+    match (left, right) {
+        // Both are numbers
+        (Element::Number(a), Element::Number(b)) => a.cmp(b),
+        // Both are vectors
+        (Element::Vector(a), Element::Vector(b)) => {
+            // Go through all the elements, comparing them until we find a difference
+            for (left, right) in a.iter().zip(b.iter()) {
+                if let Ordering::Equal = compare(left, right) {
+                    continue;
+                } else {
+                    return compare(left, right);
+                }
+            }
+
+            // Ran out of elements
+            a.len().cmp(&b.len())
+        }
+        // One is a number and the other is a vector
+        // Convert the number to a vector and compare
+        (Element::Number(a), Element::Vector(b)) => compare(
+            &Element::Vector(vec![a.into()]),
+            &Element::Vector(b.clone()),
+        ),
+        (Element::Vector(a), Element::Number(b)) => compare(
+            &Element::Vector(a.clone()),
+            &Element::Vector(vec![b.into()]),
+        ),
+    }
+}
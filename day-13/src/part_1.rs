@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+use aoc_common::SolveError;
+
+use crate::{compare, Element};
+
+/// Answer for the parsed input
+pub fn compute(pairs: &[(Element, Element)]) -> Result<i32, SolveError> {
+    let mut score = 0;
+    for (index, (left, right)) in pairs.iter().enumerate() {
+        let pair = (index + 1) as i32;
+        let cmp = compare(left, right);
+
+        if cmp == Ordering::Less {
+            score += pair;
+        }
+    }
+
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compare_numbers() {
+        let tests = [
+            (("1".to_string(), "1".to_string()), Ordering::Equal),
+            (("1".to_string(), "2".to_string()), Ordering::Less),
+            (("3".to_string(), "2".to_string()), Ordering::Greater),
+        ];
+        for ((left, right), expected) in tests {
+            assert_eq!(
+                compare(&left.parse().unwrap(), &right.parse().unwrap()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare_lists() {
+        let tests = [
+            (
+                ("[1,1,3,1,1]".to_string(), "[1,1,5,1,1]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[[1],[2,3,4]]".to_string(), "[[1],4]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[[4,4],4,4]".to_string(), "[[4,4],4,4,4]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[7,7,7,7]".to_string(), "[7,7,7]".to_string()),
+                Ordering::Greater,
+            ),
+            (("[]".to_string(), "[3]".to_string()), Ordering::Less),
+            (
+                ("[[[]]]".to_string(), "[[]]".to_string()),
+                Ordering::Greater,
+            ),
+            (
+                (
+                    "[1,[2,[3,[4,[5,6,7]]]],8,9]".to_string(),
+                    "[1,[2,[3,[4,[5,6,0]]]],8,9]".to_string(),
+                ),
+                Ordering::Greater,
+            ),
+        ];
+        for ((left, right), expected) in tests {
+            assert_eq!(
+                compare(&left.parse().unwrap(), &right.parse().unwrap()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+            13,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day13::solve_part_one(input), Ok(expected));
+        }
+    }
+}
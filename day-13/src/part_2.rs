@@ -0,0 +1,141 @@
+use aoc_common::SolveError;
+
+use crate::{compare, Element};
+
+/// Answer for the parsed input
+pub fn compute(pairs: &[(Element, Element)]) -> Result<i32, SolveError> {
+    // Add Divider Packets, [[2]] and [[6]], tagged as packets may sort level with them
+    let dividers = [2, 6].map(|n| Element::Vector(vec![Element::Vector(vec![n.into()])]));
+    let mut packets: Vec<(&Element, bool)> = pairs
+        .iter()
+        .flat_map(|(left, right)| [(left, false), (right, false)])
+        .chain(dividers.iter().map(|divider| (divider, true)))
+        .collect();
+
+    // Sort the packets
+    packets.sort_by(|(left, _), (right, _)| compare(left, right));
+
+    packets
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, divider))| *divider)
+        .try_fold(1i32, |product, (i, _)| {
+            product.checked_mul(i32::try_from(i + 1).ok()?)
+        })
+        .ok_or_else(|| SolveError::no_solution("Decoder key overflows i32"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_compare_numbers() {
+        let tests = [
+            (("1".to_string(), "1".to_string()), Ordering::Equal),
+            (("1".to_string(), "2".to_string()), Ordering::Less),
+            (("3".to_string(), "2".to_string()), Ordering::Greater),
+        ];
+        for ((left, right), expected) in tests {
+            assert_eq!(
+                compare(&left.parse().unwrap(), &right.parse().unwrap()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare_lists() {
+        let tests = [
+            (
+                ("[1,1,3,1,1]".to_string(), "[1,1,5,1,1]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[[1],[2,3,4]]".to_string(), "[[1],4]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[[4,4],4,4]".to_string(), "[[4,4],4,4,4]".to_string()),
+                Ordering::Less,
+            ),
+            (
+                ("[7,7,7,7]".to_string(), "[7,7,7]".to_string()),
+                Ordering::Greater,
+            ),
+            (("[]".to_string(), "[3]".to_string()), Ordering::Less),
+            (
+                ("[[[]]]".to_string(), "[[]]".to_string()),
+                Ordering::Greater,
+            ),
+            (
+                (
+                    "[1,[2,[3,[4,[5,6,7]]]],8,9]".to_string(),
+                    "[1,[2,[3,[4,[5,6,0]]]],8,9]".to_string(),
+                ),
+                Ordering::Greater,
+            ),
+        ];
+        for ((left, right), expected) in tests {
+            assert_eq!(
+                compare(&left.parse().unwrap(), &right.parse().unwrap()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+            140,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day13::solve_part_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_dividers() {
+        // Packets sorting level with [[2]] come before it, and are not dividers themselves
+        let input = ["[2]\n[[2]]\n"; 10].join("\n");
+        assert_eq!(Day13::solve_part_two(&input), Ok(21 * 22));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Both dividers sort after every packet, past the square root of i32::MAX
+        let input = "[1]\n[1]\n\n".repeat(23_171);
+        assert!(matches!(
+            Day13::solve_part_two(&input),
+            Err(SolveError::NoSolution(_))
+        ));
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::generate::{Generate, Random, Rng};
+
+use crate::{Day14, ENTRANCE};
+
+impl Generate for Day14 {
+    /// `size` paths of rock below and around the entrance, each of one to four straight lines
+    fn generate(rng: &mut Random, size: usize) -> String {
+        let (x, _) = ENTRANCE;
+        let depth = (size as i32).clamp(10, 150);
+        (0..size.max(1))
+            .map(|_| {
+                let mut point = (
+                    rng.gen_range(x - depth..=x + depth),
+                    rng.gen_range(2..=depth),
+                );
+                let mut points = vec![point];
+                for turn in 0..rng.gen_range(1..=4) {
+                    let length = rng.gen_range(1..=8) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                    // Alternating horizontal and vertical lines, staying below the entrance
+                    if turn % 2 == 0 {
+                        point.0 += length;
+                    } else {
+                        point.1 = (point.1 + length).max(1);
+                    }
+                    points.push(point);
+                }
+                let points = points
+                    .iter()
+                    .map(|(x, y)| format!("{},{}", x, y))
+                    .collect::<Vec<_>>();
+                format!("{}\n", points.join(" -> "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day14;
+    use aoc_common::generate::Generate;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(Day14::seeded(1, 20), Day14::seeded(1, 20));
+        assert_ne!(Day14::seeded(1, 20), Day14::seeded(2, 20));
+        for seed in 0..20 {
+            let input = Day14::seeded(seed, 20);
+            if let Err(err) = Day14::solve(&input) {
+                panic!("Seed {}: {}\n{}", seed, err, input);
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use std::fmt;
+
+use aoc_common::parse::lines;
+use aoc_common::picture::{Paint, Palette, Picture, Rgb};
+use aoc_common::simulation::{Draw, Frame};
+use aoc_common::{Grid, Params, Point, Simulation, Solution, SolveError};
+
+pub mod generate;
+pub mod part_1;
+pub mod part_2;
+
+/// Regolith Reservoir
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Vec<Point>>;
+    type PartOne = i32;
+    type PartTwo = i32;
+    const PARAMS: &'static [&'static str] = &["entrance"];
+
+    /// Parse lists of coordinates
+    fn parse(input: &str) -> Result<Vec<Vec<Point>>, SolveError> {
+        let mut coordinate_groups: Vec<Vec<Point>> = Vec::new();
+        for line in lines(input) {
+            let mut coors: Vec<Point> = Vec::new();
+            for coordinate_str in line.text.split(" -> ") {
+                let (x, y) = coordinate_str.split_once(',').ok_or_else(|| {
+                    line.error(coordinate_str, "Expected a coordinate, like <498,4>")
+                })?;
+                let coor = Point::new(
+                    line.parse_part::<u16>(x.trim())? as i32,
+                    line.parse_part::<u16>(y.trim())? as i32,
+                );
+                // Rocks only form straight lines
+                if let Some(last) = coors.last() {
+                    if last.x != coor.x && last.y != coor.y {
+                        return Err(
+                            line.error(coordinate_str, "Expected a horizontal or vertical line")
+                        );
+                    }
+                }
+                coors.push(coor);
+            }
+            coordinate_groups.push(coors);
+        }
+
+        Ok(coordinate_groups)
+    }
+
+    fn part_one(coordinate_groups: &Vec<Vec<Point>>) -> Result<i32, SolveError> {
+        part_1::compute(coordinate_groups, &Params::default())
+    }
+
+    fn part_one_with(
+        coordinate_groups: &Vec<Vec<Point>>,
+        params: &Params,
+    ) -> Result<i32, SolveError> {
+        part_1::compute(coordinate_groups, params)
+    }
+
+    fn part_two(coordinate_groups: &Vec<Vec<Point>>) -> Result<i32, SolveError> {
+        part_2::compute(coordinate_groups, &Params::default())
+    }
+
+    fn part_two_with(
+        coordinate_groups: &Vec<Vec<Point>>,
+        params: &Params,
+    ) -> Result<i32, SolveError> {
+        part_2::compute(coordinate_groups, params)
+    }
+}
+
+/// Where sand pours in from, as `(x, y)`
+const ENTRANCE: (i32, i32) = (500, 0);
+
+/// Grid Coordinate
+///
+/// Rock   - #
+/// Air    - .
+/// Source - +
+/// Sand   - o
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Air,
+    Source,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Tile::Source => '+',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+            Tile::Air => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl Palette for Tile {
+    /// Grey rock, dark air, red source and yellow sand
+    const COLOURS: &'static [Rgb] = &[[120, 110, 100], [24, 24, 40], [230, 60, 40], [240, 200, 90]];
+
+    fn colour(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// Rocks and the sand resting on them, with one more unit of sand coming to rest each step.
+#[derive(Debug, Clone)]
+pub struct Map {
+    // X Y Board, starting at x_min
+    tiles: Grid<Tile>,
+    // Source of the sand, in tile coordinates
+    entrance: Point,
+    // Units of sand at rest, and where the last one came to rest
+    resting: usize,
+    last: Option<Point>,
+}
+
+impl Map {
+    /// Map of the rocks, with a floor two below the lowest rock if `floor` is set.
+    pub fn new(paths: &[Vec<Point>], entrance: Point, floor: bool) -> Result<Self, SolveError> {
+        if entrance.y < 0 {
+            return Err(SolveError::param("entrance must not be above y = 0"));
+        }
+
+        // Get min and max coordinate values.
+        let mut x_min = entrance.x;
+        let mut x_max = entrance.x;
+        let mut y_max = entrance.y;
+        for point in paths.iter().flatten() {
+            if point.x < x_min {
+                x_min = point.x;
+            }
+            if point.x > x_max {
+                x_max = point.x;
+            }
+            if point.y > y_max {
+                y_max = point.y;
+            }
+        }
+        // Floor
+        if floor {
+            y_max += 2;
+            x_min = x_min.min(entrance.x - y_max - 1);
+            x_max = x_max.max(entrance.x + y_max + 1);
+        }
+
+        // Tiles, shifted so x_min is the first column
+        let offset = Point::new(x_min, 0);
+        let mut tiles = Grid::new(
+            (x_max - x_min + 1) as usize,
+            (y_max + 1) as usize,
+            Tile::Air,
+        );
+
+        // Floor
+        if floor {
+            for x in 0..=(x_max - x_min) {
+                tiles[Point::new(x, y_max)] = Tile::Rock;
+            }
+        }
+
+        // Rocks
+        for group in paths.iter() {
+            for (start, end) in group.iter().zip(group.iter().skip(1)) {
+                let step = (*end - *start).signum();
+                let mut rock = *start;
+                tiles[rock - offset] = Tile::Rock;
+                while rock != *end {
+                    rock += step;
+                    tiles[rock - offset] = Tile::Rock;
+                }
+            }
+        }
+
+        // Source/Entrance
+        tiles[entrance - offset] = Tile::Source;
+
+        Ok(Self {
+            tiles,
+            entrance: entrance - offset,
+            resting: 0,
+            last: None,
+        })
+    }
+}
+
+impl Map {
+    /// Sand will either settle in the window of concern, or fall off into the void.
+    /// There's also the chance of the source block being blocked.
+    fn next_sand(&mut self) -> Result<(), MovementError> {
+        let entrance = self.entrance;
+        let sand = entrance;
+
+        // Source Blocked
+        if self.tiles[entrance] == Tile::Sand {
+            return Err(MovementError::SourceBlocked);
+        }
+
+        // Move
+        let mut moved_sand = move_sand(&sand, &self.tiles);
+        let mut final_placement = sand;
+        while let Ok(coor) = moved_sand {
+            final_placement = coor;
+            moved_sand = move_sand(&coor, &self.tiles);
+        }
+
+        if let Err(MovementError::OutofBounds) = moved_sand {
+            return Err(MovementError::OutofBounds);
+        }
+
+        self.tiles[final_placement] = Tile::Sand;
+        self.last = Some(final_placement);
+        Ok(())
+    }
+}
+
+impl Simulation for Map {
+    type Snapshot = Map;
+
+    /// Drop a unit of sand, which either comes to rest or ends the simulation
+    fn step(&mut self) -> bool {
+        match self.next_sand() {
+            Ok(()) => {
+                self.resting += 1;
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn steps(&self) -> usize {
+        self.resting
+    }
+
+    fn snapshot(&self) -> Map {
+        self.clone()
+    }
+}
+
+impl Draw for Map {
+    /// The map as printed in the puzzle, following the sand as it comes to rest
+    fn draw(&self) -> Frame {
+        Frame {
+            rows: self.tiles.to_string().lines().map(str::to_owned).collect(),
+            origin: Point::ORIGIN,
+            focus: self.last.unwrap_or(self.entrance),
+            caption: format!("{} units of sand at rest", self.resting),
+        }
+    }
+}
+
+impl Paint for Map {
+    fn paint(&self) -> Picture {
+        self.tiles.paint()
+    }
+}
+
+#[derive(Debug)]
+enum MovementError {
+    RockFound,
+    SandFound,
+    OutofBounds,
+    AtRest,
+    SourceBlocked,
+}
+
+fn move_sand(sand: &Point, tiles: &Grid<Tile>) -> Result<Point, MovementError> {
+    // Down, then Down Left, then Down Right
+    for direction in [Point::DOWN, Point::DOWN_LEFT, Point::DOWN_RIGHT] {
+        match move_to(sand, direction, tiles) {
+            Ok(coor) => return Ok(coor),
+            Err(MovementError::OutofBounds) => return Err(MovementError::OutofBounds),
+            Err(_) => {}
+        }
+    }
+    Err(MovementError::AtRest)
+}
+
+fn move_to(sand: &Point, direction: Point, tiles: &Grid<Tile>) -> Result<Point, MovementError> {
+    let new_pos = *sand + direction;
+
+    match tiles.get(new_pos) {
+        Some(Tile::Rock) => Err(MovementError::RockFound),
+        Some(Tile::Sand) => Err(MovementError::SandFound),
+        Some(_) => Ok(new_pos),
+        // Too low, or too far left or right
+        None => Err(MovementError::OutofBounds),
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.tiles)
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::{Params, Point, Simulation, SolveError};
+
+use crate::{Map, ENTRANCE};
+
+/// The rocks, with sand falling into the void below them, and sand entering at (500, 0) unless `entrance` is set
+pub fn map(coordinate_groups: &[Vec<Point>], params: &Params) -> Result<Map, SolveError> {
+    let (x, y) = params.get("entrance", ENTRANCE)?;
+    Map::new(coordinate_groups, Point::new(x, y), false)
+}
+
+/// Answer for the parsed input
+pub fn compute(coordinate_groups: &[Vec<Point>], params: &Params) -> Result<i32, SolveError> {
+    let mut map = map(coordinate_groups, params)?;
+    map.run();
+    Ok(map.steps() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day14, Map};
+    use aoc_common::picture::Paint;
+    use aoc_common::{Params, Point, Simulation, Solution, SolveError};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+            24,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day14::solve_part_one(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_entrance() {
+        let input = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+        // Clear of the rocks, every unit of sand falls into the void
+        let mut params = Params::default();
+        params.set("entrance=[400, 0]").unwrap();
+        assert_eq!(Day14::solve_part_one_with(input, &params), Ok(0));
+        params.set("entrance=[500, -1]").unwrap();
+        assert!(matches!(
+            Day14::solve_part_one_with(input, &params),
+            Err(SolveError::Param(_))
+        ));
+    }
+
+    #[test]
+    fn test_map() {
+        let paths =
+            Day14::parse("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+        let mut map = Map::new(&paths, Point::new(500, 0), false).unwrap();
+        map.run_until(|map| map.steps() == 2);
+        assert_eq!(
+            map.snapshot().to_string(),
+            "......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+.....oo.#.
+#########.
+"
+        );
+        assert_eq!(map.run(), 22);
+        assert!(!map.step());
+        assert_eq!(map.steps(), 24);
+    }
+
+    #[test]
+    fn test_paint() {
+        let paths =
+            Day14::parse("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+        let mut map = Map::new(&paths, Point::new(500, 0), false).unwrap();
+        map.run_until(|map| map.steps() == 2);
+        let picture = map.paint();
+        assert_eq!((picture.width(), picture.height()), (10, 10));
+        let pixel = |x: usize, y: usize| picture.palette()[picture.pixels()[y * 10 + x] as usize];
+        assert_eq!(pixel(6, 0), [230, 60, 40]);
+        assert_eq!(pixel(5, 8), [240, 200, 90]);
+        assert_eq!(pixel(0, 9), [120, 110, 100]);
+        assert_eq!(pixel(0, 0), [24, 24, 40]);
+    }
+}
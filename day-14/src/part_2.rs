@@ -0,0 +1,36 @@
+use aoc_common::{Params, Point, Simulation, SolveError};
+
+use crate::{Map, ENTRANCE};
+
+/// The rocks, with a floor below them, and sand entering at (500, 0) unless `entrance` is set
+pub fn map(coordinate_groups: &[Vec<Point>], params: &Params) -> Result<Map, SolveError> {
+    let (x, y) = params.get("entrance", ENTRANCE)?;
+    Map::new(coordinate_groups, Point::new(x, y), true)
+}
+
+/// Answer for the parsed input
+pub fn compute(coordinate_groups: &[Vec<Point>], params: &Params) -> Result<i32, SolveError> {
+    let mut map = map(coordinate_groups, params)?;
+    map.run();
+    Ok(map.steps() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day14;
+    use aoc_common::Solution;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_compute() {
+        let tests = vec![(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+            93,
+        )];
+        for (input, expected) in tests {
+            assert_eq!(Day14::solve_part_two(input), Ok(expected));
+        }
+    }
+}
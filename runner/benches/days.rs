@@ -0,0 +1,67 @@
+use std::fs;
+use std::hint::black_box;
+use std::time::Duration;
+
+use aoc_common::input::normalise;
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use runner::registry::{self, Day};
+
+/// Seed and size of the generated input each day is also benchmarked on.
+const GENERATED: (u64, usize) = (2022, 100);
+
+/// Benchmark a day's `parse` and each part separately, on the example, the real input and a generated one.
+fn bench_day<S: Solution>(c: &mut Criterion, day: &Day) {
+    let mut group = c.benchmark_group(day.dir);
+    let (seed, size) = GENERATED;
+    let inputs = [
+        ("example", fs::read_to_string(day.example_path())),
+        ("input", fs::read_to_string(day.input_path())),
+        ("generated", Ok((day.generate)(seed, size))),
+    ];
+    for (name, input) in inputs {
+        // Real inputs are personal, so a checkout may not have one
+        let Ok(input) = input else {
+            continue;
+        };
+        // Parsed after normalising, as `timed` and `Solution::solve` do
+        let input = normalise(&input);
+        group.bench_function(format!("parse/{}", name), |b| {
+            b.iter(|| S::parse(black_box(&input)))
+        });
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => panic!("{} {}: {}", day.dir, name, err),
+        };
+        group.bench_function(format!("part_one/{}", name), |b| {
+            b.iter(|| S::part_one(black_box(&parsed)))
+        });
+        group.bench_function(format!("part_two/{}", name), |b| {
+            b.iter(|| S::part_two(black_box(&parsed)))
+        });
+    }
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($number:literal, $dir:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        fn days(c: &mut Criterion) {
+            $(
+                bench_day::<$krate::$solution>(c, registry::day($number).unwrap());
+            )*
+        }
+    };
+}
+
+runner::with_days!(bench_days);
+
+criterion_group! {
+    name = benches;
+    // Some parts take tens of milliseconds, so keep the whole suite to a few minutes
+    config = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    targets = days
+}
+criterion_main!(benches);
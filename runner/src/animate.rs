@@ -0,0 +1,415 @@
+//! Replaying simulations frame by frame in the terminal, for `--animate`.
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Stylize;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, queue, style};
+
+use aoc_common::input::normalise;
+use aoc_common::simulation::{Draw, Frame};
+use aoc_common::{Params, Point, Simulation, Solution, SolveError};
+
+/// Slowest and fastest frame rates the speed keys reach.
+const MIN_FPS: f64 = 0.5;
+const MAX_FPS: f64 = 1000.0;
+
+/// How an animation is played.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    pub fps: f64,
+    /// Simulation steps between two frames, at least 1.
+    pub steps_per_frame: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The part is not a simulation.
+    Unsupported(u8, u8),
+    NotATerminal,
+    Solve(SolveError),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsupported(day, part) => write!(
+                f,
+                "Day {:02} Part {} has no animation, try day 9, day 10 part 2 or day 14",
+                day, part
+            ),
+            Error::NotATerminal => write!(f, "Animations need a terminal"),
+            Error::Solve(err) => write!(f, "{}", err),
+            Error::Io(err) => write!(f, "Terminal error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Self {
+        Error::Solve(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// How an animation ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub steps: usize,
+    pub frames: usize,
+    /// Whether the simulation ran to the end, rather than being quit.
+    pub finished: bool,
+    /// Caption of the last frame.
+    pub caption: String,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(
+            f,
+            "{} after {} steps, {} frames in {:.1?}",
+            if self.finished { "Finished" } else { "Stopped" },
+            self.steps,
+            self.frames,
+            self.elapsed
+        )
+    }
+}
+
+/// Animate a part of a day, if it is a simulation.
+pub fn animate(
+    day: u8,
+    part: u8,
+    input: &str,
+    params: &Params,
+    options: Options,
+) -> Result<Summary, Error> {
+    let input = &*normalise(input);
+    match (day, part) {
+        (9, _) => {
+            let movements = day_09::Day09::parse(input)?;
+            let rope = match part {
+                1 => day_09::part_1::rope(&movements, params)?,
+                _ => day_09::part_2::rope(&movements, params)?,
+            };
+            play(rope, options)
+        }
+        (10, 2) => {
+            let commands = day_10::Day10::parse(input)?;
+            play(day_10::part_2::crt(&commands, params)?, options)
+        }
+        (14, _) => {
+            let paths = day_14::Day14::parse(input)?;
+            let map = match part {
+                1 => day_14::part_1::map(&paths, params)?,
+                _ => day_14::part_2::map(&paths, params)?,
+            };
+            play(map, options)
+        }
+        _ => Err(Error::Unsupported(day, part)),
+    }
+}
+
+/// Play a simulation in the terminal until it finishes and a key is pressed, or it is quit.
+///
+/// Space pauses, `n` or right steps while paused, `+` and `-` change the speed, and `q` quits.
+pub fn play<S>(mut simulation: S, options: Options) -> Result<Summary, Error>
+where
+    S: Simulation,
+    S::Snapshot: Draw,
+{
+    let mut stdout = io::stdout();
+    if !stdout.is_terminal() {
+        return Err(Error::NotATerminal);
+    }
+    terminal::enable_raw_mode()?;
+    queue!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let summary = run(&mut simulation, options, &mut stdout);
+    // Restore the terminal whether or not playing failed
+    queue!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    stdout.flush()?;
+    terminal::disable_raw_mode()?;
+    summary
+}
+
+/// Keys understood while playing.
+enum Key {
+    Quit,
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Other,
+}
+
+impl Key {
+    /// Key of an event, if it is a key press.
+    fn of(event: &Event) -> Option<Key> {
+        let Event::Key(key) = event else {
+            return None;
+        };
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        Some(match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Key::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Key::Quit,
+            KeyCode::Char(' ') => Key::Pause,
+            KeyCode::Char('n') | KeyCode::Right => Key::Step,
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Key::Faster,
+            KeyCode::Char('-') | KeyCode::Down => Key::Slower,
+            _ => Key::Other,
+        })
+    }
+}
+
+fn run<S>(simulation: &mut S, options: Options, out: &mut impl Write) -> Result<Summary, Error>
+where
+    S: Simulation,
+    S::Snapshot: Draw,
+{
+    let start = Instant::now();
+    let mut fps = options.fps.clamp(MIN_FPS, MAX_FPS);
+    let mut paused = false;
+    let mut finished = false;
+    let mut frames = 0;
+    let mut viewport: Option<Viewport> = None;
+    let mut next_frame = Instant::now();
+
+    loop {
+        let frame = simulation.snapshot().draw();
+        let (columns, rows) = terminal::size()?;
+        // The last line is for the status
+        let size = Point::new(i32::from(columns), i32::from(rows.saturating_sub(1).max(1)));
+        let viewport = viewport.get_or_insert_with(|| Viewport::centred(&frame, size));
+        viewport.size = size;
+        viewport.follow(&frame);
+        let state = if finished {
+            "finished, press any key".to_string()
+        } else if paused {
+            "PAUSED, space resume, n step, q quit".to_string()
+        } else {
+            format!("{} fps, space pause, +/- speed, q quit", fps)
+        };
+        let status = format!(
+            " {} | step {} | {} ",
+            frame.caption,
+            simulation.steps(),
+            state
+        );
+        render(out, &viewport.crop(&frame), &status, size.x as usize)?;
+        frames += 1;
+
+        let summary = |finished| Summary {
+            steps: simulation.steps(),
+            frames,
+            finished,
+            caption: frame.caption.clone(),
+            elapsed: start.elapsed(),
+        };
+        // Wait for the next frame to be due, or for a key that changes what is shown
+        let advance = loop {
+            let ready = if paused || finished {
+                true
+            } else {
+                event::poll(next_frame.saturating_duration_since(Instant::now()))?
+            };
+            if !ready {
+                break true;
+            }
+            let event = event::read()?;
+            if let Event::Resize(..) = event {
+                break false;
+            }
+            match Key::of(&event) {
+                None => {}
+                Some(Key::Quit) => return Ok(summary(false)),
+                Some(_) if finished => return Ok(summary(true)),
+                Some(Key::Pause) => {
+                    paused = !paused;
+                    break false;
+                }
+                Some(Key::Step) if paused => break true,
+                Some(Key::Faster) => {
+                    fps = (fps * 2.0).min(MAX_FPS);
+                    break false;
+                }
+                Some(Key::Slower) => {
+                    fps = (fps / 2.0).max(MIN_FPS);
+                    break false;
+                }
+                Some(_) => {}
+            }
+        };
+        if advance {
+            next_frame = Instant::now() + Duration::from_secs_f64(1.0 / fps);
+            for _ in 0..options.steps_per_frame.max(1) {
+                if !simulation.step() {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Draw the lines of a frame, then the status line in reverse video.
+fn render(out: &mut impl Write, lines: &[String], status: &str, width: usize) -> io::Result<()> {
+    queue!(out, cursor::MoveTo(0, 0))?;
+    for line in lines {
+        queue!(out, style::Print(line), cursor::MoveToNextLine(1))?;
+    }
+    let status = status.chars().take(width).collect::<String>();
+    queue!(
+        out,
+        style::PrintStyledContent(status.reverse()),
+        terminal::Clear(ClearType::UntilNewLine)
+    )?;
+    out.flush()
+}
+
+/// The part of a frame shown on the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Viewport {
+    corner: Point,
+    size: Point,
+}
+
+impl Viewport {
+    /// Viewport of `size` centred on the focus of a frame.
+    fn centred(frame: &Frame, size: Point) -> Self {
+        Self {
+            corner: frame.focus - Point::new(size.x / 2, size.y / 2),
+            size,
+        }
+    }
+
+    /// Move as little as possible to keep the focus away from the edges.
+    fn follow(&mut self, frame: &Frame) {
+        let width = frame.rows.iter().map(|row| row.chars().count()).max();
+        let frame_size = Point::new(width.unwrap_or(0) as i32, frame.rows.len() as i32);
+        self.corner = Point::new(
+            follow(
+                self.corner.x,
+                self.size.x,
+                frame.origin.x,
+                frame_size.x,
+                frame.focus.x,
+            ),
+            follow(
+                self.corner.y,
+                self.size.y,
+                frame.origin.y,
+                frame_size.y,
+                frame.focus.y,
+            ),
+        );
+    }
+
+    /// Lines of the frame within the viewport, padded with spaces to its size.
+    fn crop(&self, frame: &Frame) -> Vec<String> {
+        let skip = self.corner.x - frame.origin.x;
+        (0..self.size.y)
+            .map(|row| {
+                let y = self.corner.y + row - frame.origin.y;
+                let line = usize::try_from(y).ok().and_then(|y| frame.rows.get(y));
+                let chars = line.map_or("", String::as_str).chars();
+                // Blank columns left of the frame, then the frame, then blanks to the right
+                let blank = (-skip).clamp(0, self.size.x) as usize;
+                std::iter::repeat_n(' ', blank)
+                    .chain(chars.skip(skip.max(0) as usize))
+                    .chain(std::iter::repeat(' '))
+                    .take(self.size.x as usize)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Start of a viewport of `size` along one axis, moved from `start` to keep `focus` a quarter
+/// of the size away from the edges without going past the frame, or showing all of the frame if it fits.
+fn follow(start: i32, size: i32, frame_start: i32, frame_size: i32, focus: i32) -> i32 {
+    if frame_size <= size {
+        return frame_start;
+    }
+    let margin = size / 4;
+    start
+        .clamp(focus - size + 1 + margin, focus - margin)
+        .clamp(frame_start, frame_start + frame_size - size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_follow() {
+        let tests = vec![
+            // Whole frame fits
+            ((7, 10, 0, 8, 5), 0),
+            // Focus well inside
+            ((10, 8, 0, 100, 13), 10),
+            // Focus near the right edge, then the left
+            ((10, 8, 0, 100, 17), 12),
+            ((10, 8, 0, 100, 11), 9),
+            // Never past either end of the frame
+            ((10, 8, 0, 100, 0), 0),
+            ((10, 8, 0, 100, 99), 92),
+            ((10, 8, -50, 100, -49), -50),
+        ];
+        for ((start, size, frame_start, frame_size, focus), expected) in tests {
+            assert_eq!(
+                follow(start, size, frame_start, frame_size, focus),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_crop() {
+        let frame = Frame {
+            rows: vec!["abc".to_string(), "def".to_string()],
+            origin: Point::new(10, 20),
+            focus: Point::new(11, 20),
+            caption: String::new(),
+        };
+        let viewport = Viewport {
+            corner: Point::new(9, 19),
+            size: Point::new(3, 4),
+        };
+        assert_eq!(viewport.crop(&frame), vec!["   ", " ab", " de", "   "]);
+        let viewport = Viewport {
+            corner: Point::new(11, 20),
+            size: Point::new(4, 1),
+        };
+        assert_eq!(viewport.crop(&frame), vec!["bc  "]);
+    }
+
+    #[test]
+    fn test_centred() {
+        let frame = Frame {
+            rows: vec!["#".repeat(100); 100],
+            origin: Point::ORIGIN,
+            focus: Point::new(50, 60),
+            caption: String::new(),
+        };
+        let mut viewport = Viewport::centred(&frame, Point::new(20, 10));
+        assert_eq!(viewport.corner, Point::new(40, 55));
+        viewport.follow(&frame);
+        assert_eq!(viewport.corner, Point::new(40, 55));
+    }
+}
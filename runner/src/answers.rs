@@ -0,0 +1,237 @@
+use std::fmt::{self, Write};
+use std::path::Path;
+
+use aoc_common::{Answer, Params};
+
+use crate::registry::Day;
+
+/// A day's recorded answers, from its `answers.toml`.
+///
+/// ```toml
+/// part_1 = 24000
+/// part_2 = "MCD"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+impl Answers {
+    /// Answers from the contents of an `answers.toml`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<toml::Table>().map_err(|err| err.to_string())?;
+        let answer = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::Integer(n)) => Ok(Some(Answer::from(*n))),
+            Some(toml::Value::String(s)) => Ok(Some(match s.parse::<i128>() {
+                // Integers too wide for TOML
+                Ok(n) => Answer::Int(n),
+                // Screens span several lines, as a multi-line string
+                Err(_) if s.contains('\n') => Answer::screen(s),
+                Err(_) => Answer::from(s.as_str()),
+            })),
+            Some(other) => Err(format!(
+                "{} must be an integer or a string, found {}",
+                key,
+                other.type_str()
+            )),
+        };
+        Ok(Self {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+
+    /// Answers recorded for a day, which has none if the file is absent.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {}", path.display(), err)),
+        }
+    }
+
+    /// Recorded answer of a part, numbered from 1.
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Compare an answer with the recorded one.
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+                found: answer.to_owned(),
+            },
+        }
+    }
+}
+
+/// Outcome of checking a part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+        found: Answer,
+    },
+    /// There is no recorded answer, or no input to check it with.
+    Missing,
+    /// The part, or its input or answers, could not be read or solved.
+    Error(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail {
+                expected: expected @ Answer::Screen(_),
+                found,
+            }
+            | Verdict::Fail {
+                expected,
+                found: found @ Answer::Screen(_),
+            } => {
+                write!(f, "FAIL, expected:\n{}\nfound:\n{}", expected, found)
+            }
+            Verdict::Fail { expected, found } => {
+                write!(f, "FAIL, expected {}, found {}", expected, found)
+            }
+            Verdict::Missing => write!(f, "missing"),
+            Verdict::Error(err) => write!(f, "ERROR, {}", err),
+        }
+    }
+}
+
+/// Check each part of a day against its `answers.toml`.
+pub fn verify(day: &Day, parts: impl IntoIterator<Item = u8>) -> Vec<(u8, Verdict)> {
+    let parts = parts.into_iter();
+    let answers = match Answers::load(day.answers_path()) {
+        Ok(answers) => answers,
+        Err(err) => {
+            return parts
+                .map(|part| (part, Verdict::Error(err.clone())))
+                .collect()
+        }
+    };
+    // Without an input there is nothing to check
+    let input = match std::fs::read_to_string(day.input_path()) {
+        Ok(input) => input,
+        Err(_) => return parts.map(|part| (part, Verdict::Missing)).collect(),
+    };
+    // Answers are for the puzzle as published, so without any parameters
+    parts
+        .map(|part| {
+            let verdict = match day.solve(part, &input, &Params::default()) {
+                Ok(solved) => answers.check(part, &solved.answer),
+                Err(err) => Verdict::Error(err.to_string()),
+            };
+            (part, verdict)
+        })
+        .collect()
+}
+
+/// Summary table of verdicts, one row per part, followed by the counts.
+pub fn table(verdicts: &[(u8, u8, Verdict)]) -> String {
+    let mut table = format!("{:<4} {:>4}  {}\n", "Day", "Part", "Result");
+    for (day, part, verdict) in verdicts {
+        writeln!(
+            table,
+            "{:<4} {:>4}  {}",
+            format!("{:02}", day),
+            part,
+            verdict
+        )
+        .unwrap();
+    }
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|(_, _, v)| f(v)).count();
+    write!(
+        table,
+        "{} passed, {} failed, {} missing",
+        count(|v| *v == Verdict::Pass),
+        count(Verdict::is_failure),
+        count(|v| *v == Verdict::Missing),
+    )
+    .unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_parse() {
+        let tests = vec![
+            (
+                "part_1 = 24000\npart_2 = \"MCD\"",
+                Ok(Answers {
+                    part_1: Some(Answer::from(24000)),
+                    part_2: Some(Answer::from("MCD")),
+                }),
+            ),
+            (
+                "part_1 = 7",
+                Ok(Answers {
+                    part_1: Some(Answer::from(7)),
+                    part_2: None,
+                }),
+            ),
+            (
+                "part_2 = '''\n#..\n.#.'''",
+                Ok(Answers {
+                    part_1: None,
+                    part_2: Some(Answer::screen("#..\n.#.")),
+                }),
+            ),
+            (
+                "part_1 = true",
+                Err("part_1 must be an integer or a string, found boolean".to_string()),
+            ),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(Answers::parse(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let answers = Answers::parse("part_1 = 24000").unwrap();
+        let verdicts = vec![
+            (1, 1, answers.check(1, &Answer::from(24000))),
+            (1, 2, answers.check(2, &Answer::from(45000))),
+            (
+                2,
+                1,
+                Answers::parse("part_1 = 15")
+                    .unwrap()
+                    .check(1, &Answer::from(16)),
+            ),
+        ];
+        assert_eq!(
+            table(&verdicts),
+            "Day  Part  Result
+01      1  pass
+01      2  missing
+02      1  FAIL, expected 15, found 16
+1 passed, 1 failed, 1 missing"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Running every implementation of a part on the same inputs, for `--cross-check`.
+use std::fmt;
+
+use aoc_common::variant::Check;
+use aoc_common::{Params, SolveError};
+
+use crate::registry::Day;
+
+/// Where an input came from, so a disagreement can be reproduced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Read from a file, or stdin
+    File(String),
+    Generated {
+        seed: u64,
+        size: usize,
+    },
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(name) => write!(f, "{}", name),
+            Source::Generated { seed, size } => write!(f, "--seed {} --size {}", seed, size),
+        }
+    }
+}
+
+/// How the implementations of a part compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// There are no variants of the part to compare with.
+    Alone,
+    /// Every implementation gave the same answers for this many inputs.
+    Agreed(usize),
+    /// The first input they gave different answers for.
+    Disagreed {
+        source: Source,
+        input: String,
+        check: Check,
+    },
+    /// An input could not be parsed.
+    Failed { source: Source, err: SolveError },
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Disagreed { .. } | Outcome::Failed { .. })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Alone => write!(f, "no variants"),
+            Outcome::Agreed(inputs) => write!(f, "agreed on {} inputs", inputs),
+            Outcome::Disagreed {
+                source,
+                input,
+                check,
+            } => {
+                write!(f, "DISAGREED on {}", source)?;
+                for line in check.to_string().lines() {
+                    write!(f, "\n  {}", line)?;
+                }
+                write!(f, "\ninput:\n{}", input.trim_end())
+            }
+            Outcome::Failed { source, err } => write!(f, "ERROR on {}, {}", source, err),
+        }
+    }
+}
+
+/// Answer a part of a day with every implementation, input by input, stopping at the first
+/// disagreement.
+pub fn cross_check(
+    day: &Day,
+    part: u8,
+    params: &Params,
+    inputs: impl IntoIterator<Item = (Source, String)>,
+) -> Outcome {
+    let mut agreed = 0;
+    for (source, input) in inputs {
+        let check = match (day.check)(part, &input, params) {
+            Ok(check) => check,
+            Err(err) => return Outcome::Failed { source, err },
+        };
+        if !check.compared() {
+            return Outcome::Alone;
+        }
+        if !check.agrees() {
+            return Outcome::Disagreed {
+                source,
+                input,
+                check,
+            };
+        }
+        agreed += 1;
+    }
+    Outcome::Agreed(agreed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use aoc_common::Answer;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn generated(day: &Day, seeds: std::ops::Range<u64>) -> Vec<(Source, String)> {
+        seeds
+            .map(|seed| {
+                let size = 30;
+                (Source::Generated { seed, size }, (day.generate)(seed, size))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let params = Params::default();
+        let day = registry::day(6).unwrap();
+        assert_eq!(
+            cross_check(day, 2, &params, generated(day, 0..10)),
+            Outcome::Agreed(10)
+        );
+        let day = registry::day(1).unwrap();
+        assert_eq!(
+            cross_check(day, 1, &params, generated(day, 0..10)),
+            Outcome::Alone
+        );
+
+        let day = registry::day(8).unwrap();
+        let source = Source::File("forest.txt".to_string());
+        let outcome = cross_check(day, 1, &params, [(source.clone(), "12\n3x".to_string())]);
+        assert!(matches!(&outcome, Outcome::Failed { source: failed, .. } if *failed == source));
+        assert!(outcome.is_failure());
+    }
+
+    #[test]
+    fn test_display() {
+        let outcome = Outcome::Disagreed {
+            source: Source::Generated { seed: 3, size: 20 },
+            input: "abcd\n".to_string(),
+            check: Check {
+                part: 1,
+                answers: vec![
+                    ("solution", Ok(Answer::Int(4))),
+                    ("window", Ok(Answer::Int(5))),
+                ],
+            },
+        };
+        assert_eq!(
+            outcome.to_string(),
+            "DISAGREED on --seed 3 --size 20\n  solution: 4\n  window: 5\ninput:\nabcd"
+        );
+        assert_eq!(Outcome::Agreed(7).to_string(), "agreed on 7 inputs");
+    }
+}
@@ -0,0 +1,369 @@
+//! Exporting simulations as PNG frames or an animated GIF, for `--export`.
+use std::borrow::Cow;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use aoc_common::input::normalise;
+use aoc_common::picture::{Paint, Picture};
+use aoc_common::{Params, Simulation, Solution, SolveError};
+
+/// How long the last frame of a GIF is shown before it loops, in hundredths of a second.
+const LAST_FRAME_DELAY: u16 = 200;
+
+/// What to export, and where.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub dir: PathBuf,
+    /// Write one animated GIF, rather than a PNG per frame.
+    pub gif: bool,
+    /// Pixels per tile, at least 1.
+    pub scale: usize,
+    /// Frames per second of the GIF.
+    pub fps: f64,
+    /// Simulation steps between two frames, at least 1.
+    pub steps_per_frame: usize,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// The part is not a simulation that can be painted.
+    Unsupported(u8, u8),
+    /// Frames of a GIF must all have the size of the first.
+    Size {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    Solve(SolveError),
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unsupported(day, part) => write!(
+                f,
+                "Day {:02} Part {} cannot be exported, try day 8, 9, 12 or 14",
+                day, part
+            ),
+            Error::Size { expected, found } => write!(
+                f,
+                "Frame of {}x{} pixels, expected {}x{} like the first",
+                found.0, found.1, expected.0, expected.1
+            ),
+            Error::Solve(err) => write!(f, "{}", err),
+            Error::Io(err) => write!(f, "Could not write the export: {}", err),
+            Error::Png(err) => write!(f, "Could not encode a PNG: {}", err),
+            Error::Gif(err) => write!(f, "Could not encode the GIF: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Self {
+        Error::Solve(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(err: png::EncodingError) -> Self {
+        Error::Png(err)
+    }
+}
+
+impl From<gif::EncodingError> for Error {
+    fn from(err: gif::EncodingError) -> Self {
+        Error::Gif(err)
+    }
+}
+
+/// What was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exported {
+    pub frames: usize,
+    /// The GIF, or the directory of PNGs.
+    pub path: PathBuf,
+}
+
+impl fmt::Display for Exported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} frames written to {}",
+            self.frames,
+            self.path.display()
+        )
+    }
+}
+
+/// Export a part of a day, if it is a simulation that can be painted.
+pub fn export(
+    day: u8,
+    part: u8,
+    input: &str,
+    params: &Params,
+    options: &Options,
+) -> Result<Exported, Error> {
+    let input = &*normalise(input);
+    let name = format!("day-{:02}-part-{}", day, part);
+    let steps = options.steps_per_frame;
+    match (day, part) {
+        (8, _) => {
+            let forest = day_08::Day08::parse(input)?;
+            write(&name, frames(day_08::Survey::new(&forest), steps), options)
+        }
+        (9, _) => {
+            let movements = day_09::Day09::parse(input)?;
+            let rope = match part {
+                1 => day_09::part_1::rope(&movements, params)?,
+                _ => day_09::part_2::rope(&movements, params)?,
+            };
+            write(&name, frames(rope, steps), options)
+        }
+        (12, _) => {
+            let board = day_12::Day12::parse(input)?;
+            let search = match part {
+                1 => day_12::part_1::search(&board)?,
+                _ => day_12::part_2::search(&board),
+            };
+            write(&name, frames(search, steps), options)
+        }
+        (14, _) => {
+            let paths = day_14::Day14::parse(input)?;
+            let map = match part {
+                1 => day_14::part_1::map(&paths, params)?,
+                _ => day_14::part_2::map(&paths, params)?,
+            };
+            write(&name, frames(map, steps), options)
+        }
+        _ => Err(Error::Unsupported(day, part)),
+    }
+}
+
+/// Snapshots of a simulation, `steps` steps apart, from the first to the last.
+fn frames<S: Simulation>(mut simulation: S, steps: usize) -> impl Iterator<Item = S::Snapshot> {
+    let mut started = false;
+    std::iter::from_fn(move || {
+        if started {
+            let taken = (0..steps.max(1)).take_while(|_| simulation.step()).count();
+            if taken == 0 {
+                return None;
+            }
+        }
+        started = true;
+        Some(simulation.snapshot())
+    })
+}
+
+/// Paint the snapshots into `options.dir`, as `<name>.gif` or `<name>-00000.png` onwards.
+fn write<P: Paint>(
+    name: &str,
+    snapshots: impl Iterator<Item = P>,
+    options: &Options,
+) -> Result<Exported, Error> {
+    fs::create_dir_all(&options.dir)?;
+    let pictures = snapshots.map(|snapshot| snapshot.paint().scaled(options.scale.max(1)));
+    if options.gif {
+        let path = options.dir.join(format!("{}.gif", name));
+        let mut file = BufWriter::new(File::create(&path)?);
+        let frames = write_gif(&mut file, pictures, options.fps)?;
+        file.flush()?;
+        Ok(Exported { frames, path })
+    } else {
+        let mut frames = 0;
+        for (index, picture) in pictures.enumerate() {
+            let path = options.dir.join(format!("{}-{:05}.png", name, index));
+            write_png(BufWriter::new(File::create(path)?), &picture)?;
+            frames += 1;
+        }
+        Ok(Exported {
+            frames,
+            path: options.dir.clone(),
+        })
+    }
+}
+
+/// Encode a picture as an indexed PNG.
+fn write_png(out: impl Write, picture: &Picture) -> Result<(), png::EncodingError> {
+    let mut encoder = png::Encoder::new(out, picture.width() as u32, picture.height() as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(picture.palette().concat());
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(picture.pixels())?;
+    writer.finish()
+}
+
+/// Encode pictures of the same size as a looping GIF, holding the last one a while.
+///
+/// Returns the number of frames.
+fn write_gif(
+    out: impl Write,
+    pictures: impl Iterator<Item = Picture>,
+    fps: f64,
+) -> Result<usize, Error> {
+    // In hundredths of a second, which viewers treat as slow below 2
+    let delay = (100.0 / fps).round().clamp(2.0, f64::from(u16::MAX)) as u16;
+    let mut pictures = pictures.peekable();
+    let Some(first) = pictures.peek() else {
+        return Ok(0);
+    };
+    let size = (first.width(), first.height());
+    let dimension = |length: usize| {
+        u16::try_from(length).map_err(|_| {
+            gif::EncodingError::from(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} pixels is too large for a GIF", length),
+            ))
+        })
+    };
+    let (width, height) = (dimension(size.0)?, dimension(size.1)?);
+    let mut encoder = gif::Encoder::new(out, width, height, &first.palette().concat())?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    let mut frames = 0;
+    while let Some(picture) = pictures.next() {
+        let found = (picture.width(), picture.height());
+        if found != size {
+            return Err(Error::Size {
+                expected: size,
+                found,
+            });
+        }
+        let frame = gif::Frame {
+            width,
+            height,
+            buffer: Cow::Borrowed(picture.pixels()),
+            delay: if pictures.peek().is_some() {
+                delay
+            } else {
+                delay.max(LAST_FRAME_DELAY)
+            },
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame)?;
+        frames += 1;
+    }
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::picture::{Palette, Rgb};
+    use aoc_common::{Grid, Point};
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Shade(u8);
+
+    impl Palette for Shade {
+        const COLOURS: &'static [Rgb] = &[[0, 0, 0], [128, 128, 128], [255, 255, 255]];
+
+        fn colour(&self) -> u8 {
+            self.0
+        }
+    }
+
+    /// Lights one more cell of a row each step
+    struct Fill(Grid<Shade>, usize);
+
+    impl Simulation for Fill {
+        type Snapshot = Grid<Shade>;
+
+        fn step(&mut self) -> bool {
+            if self.1 == self.0.width() {
+                return false;
+            }
+            self.0[Point::new(self.1 as i32, 0)] = Shade(2);
+            self.1 += 1;
+            true
+        }
+
+        fn steps(&self) -> usize {
+            self.1
+        }
+
+        fn snapshot(&self) -> Grid<Shade> {
+            self.0.clone()
+        }
+    }
+
+    fn fill() -> Fill {
+        Fill(Grid::new(3, 2, Shade(1)), 0)
+    }
+
+    #[test]
+    fn test_frames() {
+        let lit = |frames: Vec<Grid<Shade>>| {
+            frames
+                .iter()
+                .map(|grid| grid.row(0).iter().filter(|shade| shade.0 == 2).count())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lit(frames(fill(), 1).collect()), vec![0, 1, 2, 3]);
+        // Always ending with the last snapshot
+        assert_eq!(lit(frames(fill(), 2).collect()), vec![0, 2, 3]);
+        assert_eq!(lit(frames(fill(), 5).collect()), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut png = Vec::new();
+        let picture = fill().snapshot().paint().scaled(2);
+        write_png(&mut png, &picture).unwrap();
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (6, 4));
+        assert_eq!(info.color_type, png::ColorType::Indexed);
+        assert_eq!(
+            info.palette.as_deref(),
+            Some(&[0, 0, 0, 128, 128, 128, 255, 255, 255][..])
+        );
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, vec![1; 24]);
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let mut gif = Vec::new();
+        let pictures = frames(fill(), 1).map(|snapshot| snapshot.paint());
+        assert_eq!(write_gif(&mut gif, pictures, 20.0).unwrap(), 4);
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (3, 2));
+        let mut delays = Vec::new();
+        let mut last = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+            last = frame.buffer.to_vec();
+        }
+        assert_eq!(delays, vec![5, 5, 5, LAST_FRAME_DELAY]);
+        assert_eq!(last, vec![2, 2, 2, 1, 1, 1]);
+
+        let pictures = [Grid::new(1, 1, Shade(0)), Grid::new(2, 1, Shade(0))];
+        assert!(matches!(
+            write_gif(Vec::new(), pictures.iter().map(Paint::paint), 20.0),
+            Err(Error::Size {
+                expected: (1, 1),
+                found: (2, 1)
+            })
+        ));
+    }
+}
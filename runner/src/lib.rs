@@ -0,0 +1,76 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub mod animate;
+pub mod answers;
+pub mod cross_check;
+pub mod export;
+pub mod pool;
+pub mod registry;
+pub mod timing;
+
+pub use registry::{Day, DAYS};
+
+/// Inclusive range of days, written as `7` or `3-7`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(pub RangeInclusive<u8>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day: <{}>", s))
+        };
+        let range = match s.split_once('-') {
+            Some((start, end)) => parse(start)?..=parse(end)?,
+            None => {
+                let day = parse(s)?;
+                day..=day
+            }
+        };
+        if range.is_empty() {
+            return Err(format!("empty day range: <{}>", s));
+        }
+        Ok(Days(range))
+    }
+}
+
+impl Days {
+    /// Registered days within the range, or the first day that is missing.
+    pub fn resolve(&self) -> Result<Vec<&'static Day>, u8> {
+        self.0
+            .clone()
+            .map(|number| registry::day(number).ok_or(number))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_parse_days() {
+        let tests = vec![
+            ("7", Ok(Days(7..=7))),
+            ("3-7", Ok(Days(3..=7))),
+            ("7-3", Err("empty day range: <7-3>".to_string())),
+            ("x", Err("invalid day: <x>".to_string())),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(input.parse::<Days>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let days = Days(1..=14).resolve().unwrap();
+        assert_eq!(days.len(), 14);
+        assert_eq!(Days(13..=15).resolve().unwrap_err(), 15);
+    }
+}
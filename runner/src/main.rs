@@ -0,0 +1,440 @@
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::Parser;
+
+use aoc_common::input::{InputError, Source};
+use aoc_common::report::{Failure, Format, Report};
+use aoc_common::{Answer, Params};
+use runner::animate;
+use runner::answers;
+use runner::cross_check;
+use runner::export;
+use runner::pool::{self, Outcome};
+use runner::timing::{self, Timing};
+use runner::{Day, Days, DAYS};
+
+/// Run any day and part of Advent of Code 2022
+#[derive(Parser, Debug)]
+#[command(version)]
+struct Args {
+    /// Day, or inclusive range of days, to run (e.g. `7` or `3-7`)
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<Days>,
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Run every day
+    #[arg(short, long)]
+    all: bool,
+    /// Input file for a single day, or `-` for stdin [default: the day's input.txt]
+    #[arg(short, long)]
+    input: Option<String>,
+    /// Use each day's example.txt instead of its input.txt
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+    /// Solve inputs generated from this seed, instead of each day's input.txt
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    seed: Option<u64>,
+    /// How large generated inputs are, like their number of lines or the side of their grid
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+    /// Print the generated input of a single day, instead of solving it
+    #[arg(long, requires = "seed")]
+    generate: bool,
+    /// Check answers against each day's answers.toml, instead of printing them
+    #[arg(long, conflicts_with_all = ["input", "example", "params", "seed"])]
+    verify: bool,
+    /// Answer each part with every implementation of it, on the day's input and --cases generated inputs, reporting any disagreement
+    #[arg(long, conflicts_with_all = ["verify", "generate", "format", "time", "jobs"])]
+    cross_check: bool,
+    /// Number of generated inputs for --cross-check, from --seed onwards [default seed: 0]
+    #[arg(long, default_value_t = 100)]
+    cases: u64,
+    /// Set a puzzle parameter, overriding each day's params.toml (e.g. `-P knot_count=3`)
+    #[arg(short = 'P', long = "param", value_name = "NAME=VALUE")]
+    params: Vec<String>,
+    /// Print answers as `text`, or `json` for one JSON object per solved part
+    #[arg(short, long, default_value = "text", conflicts_with = "verify")]
+    format: Format,
+    /// Print how long parsing and computing each part took
+    #[arg(short, long, conflicts_with = "jobs")]
+    time: bool,
+    /// Solve parts on this many threads, then report every answer, time and failure [default: one per CPU]
+    #[arg(short, long, num_args = 0..=1, default_missing_value = "0", conflicts_with = "verify")]
+    jobs: Option<usize>,
+    /// Replay a single part frame by frame in the terminal, for days 9, 10 and 14
+    #[arg(long, requires = "part", conflicts_with_all = ["all", "verify", "jobs", "time", "format"])]
+    animate: bool,
+    /// Write a single part's frames as PNG images to this directory, for days 8, 9, 12 and 14
+    #[arg(long, value_name = "DIR", requires = "part", conflicts_with_all = ["all", "verify", "jobs", "time", "format", "animate"])]
+    export: Option<PathBuf>,
+    /// Write one animated GIF to the --export directory instead of PNG images
+    #[arg(long, requires = "export")]
+    gif: bool,
+    /// Pixels per tile of --export
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+    scale: u64,
+    /// Frames per second of --animate and --export --gif
+    #[arg(long, default_value_t = 30.0, value_parser = fps)]
+    fps: f64,
+    /// Simulation steps between two frames of --animate and --export
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    steps_per_frame: u64,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let days = match &args.day {
+        Some(days) => match days.resolve() {
+            Ok(days) => days,
+            Err(number) => {
+                eprintln!("Day {:02} is not registered", number);
+                std::process::exit(1);
+            }
+        },
+        None => DAYS.iter().collect(),
+    };
+    if args.input.is_some() && days.len() > 1 {
+        eprintln!("--input can only be used with a single day");
+        std::process::exit(1);
+    }
+    if let (Some(seed), true) = (args.seed, args.generate) {
+        let [day] = days[..] else {
+            eprintln!("--generate can only be used with a single day");
+            std::process::exit(1);
+        };
+        print!("{}", (day.generate)(seed, args.size));
+        return;
+    }
+    let parts = match args.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    if args.verify {
+        let verdicts = days
+            .iter()
+            .flat_map(|day| {
+                answers::verify(day, parts.clone())
+                    .into_iter()
+                    .map(|(part, verdict)| (day.number, part, verdict))
+            })
+            .collect::<Vec<_>>();
+        println!("{}", answers::table(&verdicts));
+        if verdicts.iter().any(|(_, _, verdict)| verdict.is_failure()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.cross_check {
+        if !cross_check(&args, &days, parts) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.animate {
+        if let Err(err) = animate(&args, &days) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(dir) = &args.export {
+        if let Err(err) = export(&args, &days, dir) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(jobs) = args.jobs {
+        let jobs = NonZeroUsize::new(jobs)
+            .or_else(|| std::thread::available_parallelism().ok())
+            .unwrap_or(NonZeroUsize::MIN);
+        if !parallel(&args, &days, parts, jobs) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut failed = false;
+    let mut timings = Vec::new();
+    for day in days {
+        let input = match input(&args, day) {
+            Ok(input) => input,
+            Err(err) => {
+                match args.format {
+                    Format::Text => eprintln!("Day {:02}: {}", day.number, err),
+                    Format::Json => {
+                        for part in parts.clone() {
+                            println!("{}", Failure::new(day.number, part, &err, false));
+                        }
+                    }
+                }
+                failed = true;
+                continue;
+            }
+        };
+        for part in parts.clone() {
+            let params = match params(day, part, &args.params) {
+                Ok(params) => params,
+                Err(err) => {
+                    failure(args.format, day.number, part, err);
+                    failed = true;
+                    continue;
+                }
+            };
+            let solved = match day.solve(part, &input, &params) {
+                Ok(solved) => solved,
+                Err(err) => {
+                    failure(args.format, day.number, part, err);
+                    failed = true;
+                    continue;
+                }
+            };
+            timings.push(Timing::new(day.number, part, &solved));
+            match (args.format, &solved.answer) {
+                (Format::Json, _) => {
+                    let elapsed = solved.total();
+                    let report = Report::new(day.number, part, solved.answer, &input, elapsed);
+                    println!("{}", report);
+                }
+                (Format::Text, Answer::Screen(_)) => {
+                    println!("Day {:02} Part {}:\n{}", day.number, part, solved.answer)
+                }
+                (Format::Text, _) => {
+                    println!("Day {:02} Part {}: {}", day.number, part, solved.answer)
+                }
+            }
+        }
+    }
+    // Reports already carry their times, and the table would break the JSON
+    if args.time && args.format == Format::Text && !timings.is_empty() {
+        println!("\n{}", timing::table(&timings));
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Print a part that was not solved, as a [`Failure`] in JSON mode.
+fn failure(format: Format, day: u8, part: u8, err: impl std::fmt::Display) {
+    match format {
+        Format::Text => eprintln!("Day {:02} Part {}: {}", day, part, err),
+        Format::Json => println!("{}", Failure::new(day, part, err, false)),
+    }
+}
+
+/// Input of a day, from `--input`, `--example` or `--seed` if given.
+fn input(args: &Args, day: &Day) -> Result<String, InputError> {
+    if let Some(seed) = args.seed {
+        return Ok((day.generate)(seed, args.size));
+    }
+    let default = if args.example {
+        day.example_path()
+    } else {
+        day.input_path()
+    };
+    Source::new(args.input.as_deref(), default).read()
+}
+
+/// Solve every part on a pool of threads, and print the outcomes in order of day and part.
+///
+/// Returns whether every part was solved.
+fn parallel(
+    args: &Args,
+    days: &[&'static Day],
+    parts: RangeInclusive<u8>,
+    jobs: NonZeroUsize,
+) -> bool {
+    let start = Instant::now();
+    let inputs = days
+        .iter()
+        .map(|day| input(args, day).map_err(|err| err.to_string()))
+        .collect::<Vec<_>>();
+    let tasks = days
+        .iter()
+        .zip(&inputs)
+        .flat_map(|(day, input)| {
+            parts.clone().map(move |part| {
+                let params = params(day, part, &args.params);
+                (*day, part, input, params)
+            })
+        })
+        .collect::<Vec<_>>();
+    let results = pool::map(&tasks, jobs, |(day, part, input, params)| {
+        let input = input.as_ref().map_err(Clone::clone)?;
+        let params = params.as_ref().map_err(Clone::clone)?;
+        day.solve(*part, input, params)
+            .map_err(|err| err.to_string())
+    });
+    let elapsed = start.elapsed();
+
+    let outcomes = tasks
+        .iter()
+        .zip(results)
+        .map(|((day, part, input, _), result)| {
+            let outcome = match result {
+                Ok(Ok(solved)) => Outcome::Solved(solved),
+                Ok(Err(err)) => Outcome::Failed(err),
+                Err(message) => Outcome::Panicked(message),
+            };
+            (day.number, *part, outcome, input)
+        })
+        .collect::<Vec<_>>();
+    match args.format {
+        Format::Text => {
+            let outcomes = outcomes
+                .iter()
+                .map(|(day, part, outcome, _)| (*day, *part, outcome.clone()))
+                .collect::<Vec<_>>();
+            println!("{}", pool::table(&outcomes, elapsed));
+        }
+        Format::Json => {
+            for (day, part, outcome, input) in &outcomes {
+                match (outcome, input) {
+                    (Outcome::Solved(solved), Ok(input)) => {
+                        let answer = solved.answer.clone();
+                        println!(
+                            "{}",
+                            Report::new(*day, *part, answer, input, solved.total())
+                        );
+                    }
+                    (Outcome::Solved(_), Err(_)) => unreachable!("solved without an input"),
+                    (Outcome::Failed(err), _) => {
+                        println!("{}", Failure::new(*day, *part, err, false))
+                    }
+                    (Outcome::Panicked(message), _) => {
+                        println!("{}", Failure::new(*day, *part, message, true))
+                    }
+                }
+            }
+        }
+    }
+    outcomes
+        .iter()
+        .all(|(_, _, outcome, _)| !outcome.is_failure())
+}
+
+/// Cross-check every part of the days on their input, then on generated inputs, printing how each went.
+///
+/// Returns whether every implementation agreed.
+fn cross_check(args: &Args, days: &[&'static Day], parts: RangeInclusive<u8>) -> bool {
+    let mut agreed = true;
+    for day in days {
+        // The day's own file, unless there is none and none was asked for
+        let default = if args.example {
+            day.example_path()
+        } else {
+            day.input_path()
+        };
+        let file = match (&args.input, default.exists()) {
+            (None, false) => None,
+            (name, _) => match Source::new(name.as_deref(), &default).read() {
+                Ok(input) => {
+                    let name = name
+                        .clone()
+                        .unwrap_or_else(|| default.display().to_string());
+                    Some((cross_check::Source::File(name), input))
+                }
+                Err(err) => {
+                    eprintln!("Day {:02}: {}", day.number, err);
+                    agreed = false;
+                    continue;
+                }
+            },
+        };
+        let start = args.seed.unwrap_or(0);
+        let generated = (start..start.saturating_add(args.cases)).map(|seed| {
+            let source = cross_check::Source::Generated {
+                seed,
+                size: args.size,
+            };
+            (source, (day.generate)(seed, args.size))
+        });
+        for part in parts.clone() {
+            let params = match params(day, part, &args.params) {
+                Ok(params) => params,
+                Err(err) => {
+                    eprintln!("Day {:02} Part {}: {}", day.number, part, err);
+                    agreed = false;
+                    continue;
+                }
+            };
+            let inputs = file.clone().into_iter().chain(generated.clone());
+            let outcome = cross_check::cross_check(day, part, &params, inputs);
+            println!("Day {:02} Part {}: {}", day.number, part, outcome);
+            agreed &= !outcome.is_failure();
+        }
+    }
+    agreed
+}
+
+/// Animate the single day and part asked for, then print how the animation ended.
+fn animate(args: &Args, days: &[&'static Day]) -> Result<(), Box<dyn std::error::Error>> {
+    let [day] = days else {
+        return Err("--animate can only be used with a single day".into());
+    };
+    let part = args.part.unwrap_or(1);
+    let input = input(args, day)?;
+    let params = params(day, part, &args.params)?;
+    let options = animate::Options {
+        fps: args.fps,
+        steps_per_frame: args.steps_per_frame as usize,
+    };
+    let summary = animate::animate(day.number, part, &input, &params, options)?;
+    println!("Day {:02} Part {}: {}", day.number, part, summary);
+    Ok(())
+}
+
+/// Export the frames of the single day and part asked for, then print where they were written.
+fn export(
+    args: &Args,
+    days: &[&'static Day],
+    dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let [day] = days else {
+        return Err("--export can only be used with a single day".into());
+    };
+    let part = args.part.unwrap_or(1);
+    let input = input(args, day)?;
+    let params = params(day, part, &args.params)?;
+    let options = export::Options {
+        dir: dir.to_path_buf(),
+        gif: args.gif,
+        scale: args.scale as usize,
+        fps: args.fps,
+        steps_per_frame: args.steps_per_frame as usize,
+    };
+    let exported = export::export(day.number, part, &input, &params, &options)?;
+    println!("Day {:02} Part {}: {}", day.number, part, exported);
+    Ok(())
+}
+
+/// Parameters of a part: the day's params.toml, overridden by `--param` flags, all known to the day.
+fn params(day: &Day, part: u8, overrides: &[String]) -> Result<Params, String> {
+    let mut params = Params::load(day.params_path(), part).map_err(|err| err.to_string())?;
+    for assignment in overrides {
+        params.set(assignment).map_err(|err| err.to_string())?;
+    }
+    params.check(day.params).map_err(|err| err.to_string())?;
+    Ok(params)
+}
+
+/// Frames per second, which must be a positive finite number to time frames by.
+fn fps(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(fps) if fps.is_finite() && fps > 0.0 => Ok(fps),
+        _ => Err(format!(
+            "<{}> is not a positive number of frames per second",
+            s
+        )),
+    }
+}
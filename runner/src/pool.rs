@@ -0,0 +1,193 @@
+//! Solving many parts at once on a fixed number of threads, for `--jobs`.
+use std::any::Any;
+use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::Answer;
+
+use crate::registry::Solved;
+use crate::timing::duration;
+
+/// Apply `f` to every task on up to `jobs` threads, with the results in the order of the tasks.
+///
+/// A task that panics does not stop the others; its panic message takes the place of its result.
+/// The panic hook is silenced meanwhile, as every panic is reported with the results instead.
+pub fn map<T: Sync, R: Send>(
+    tasks: &[T],
+    jobs: NonZeroUsize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let next = AtomicUsize::new(0);
+    let mut results = thread::scope(|scope| {
+        let workers = (0..jobs.get().min(tasks.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    // Take the next task until there are none left
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(task) = tasks.get(index) else {
+                            return results;
+                        };
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| f(task)))
+                            .map_err(|payload| panic_message(payload.as_ref()));
+                        results.push((index, result));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("panics are caught"))
+            .collect::<Vec<_>>()
+    });
+    panic::set_hook(hook);
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Message of a caught panic, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// How a single part of a run went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Solved),
+    /// The input or parameters could not be read, or the part has no solution.
+    Failed(String),
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_))
+    }
+}
+
+/// Report of a run, one row per part in the order given, followed by the counts and times.
+///
+/// `elapsed` is the wall time of the whole run, which is less than the sum of the parts on many threads.
+pub fn table(outcomes: &[(u8, u8, Outcome)], elapsed: Duration) -> String {
+    let mut table = format!("{:<4} {:>4} {:>10}  {}\n", "Day", "Part", "Time", "Answer");
+    for (day, part, outcome) in outcomes {
+        let (time, answer) = match outcome {
+            Outcome::Solved(solved) => (duration(solved.total()), answer(&solved.answer)),
+            Outcome::Failed(err) => ("-".to_string(), format!("ERROR, {}", err)),
+            Outcome::Panicked(message) => ("-".to_string(), format!("PANIC, {}", message)),
+        };
+        writeln!(
+            table,
+            "{:<4} {:>4} {:>10}  {}",
+            format!("{:02}", day),
+            part,
+            time,
+            answer
+        )
+        .unwrap();
+    }
+
+    let count = |matches: fn(&Outcome) -> bool| {
+        outcomes
+            .iter()
+            .filter(|(_, _, outcome)| matches(outcome))
+            .count()
+    };
+    let solving: Duration = outcomes
+        .iter()
+        .filter_map(|(_, _, outcome)| match outcome {
+            Outcome::Solved(solved) => Some(solved.total()),
+            _ => None,
+        })
+        .sum();
+    write!(
+        table,
+        "{} solved, {} failed, {} panicked, in {} ({} solving)",
+        count(|outcome| matches!(outcome, Outcome::Solved(_))),
+        count(|outcome| matches!(outcome, Outcome::Failed(_))),
+        count(|outcome| matches!(outcome, Outcome::Panicked(_))),
+        duration(elapsed),
+        duration(solving)
+    )
+    .unwrap();
+    table
+}
+
+/// Answer on a single line, with the rows of a screen indented below the others.
+fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Screen(rows) => rows.join(&format!("\n{:22}", "")),
+        _ => answer.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_map() {
+        let tasks = (0..20).collect::<Vec<u32>>();
+        let results = map(&tasks, NonZeroUsize::new(4).unwrap(), |n| {
+            if *n == 7 {
+                panic!("unlucky {}", n);
+            }
+            n * 2
+        });
+        assert_eq!(results.len(), 20);
+        for (n, result) in tasks.iter().zip(results) {
+            match n {
+                7 => assert_eq!(result, Err("unlucky 7".to_string())),
+                _ => assert_eq!(result, Ok(n * 2)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let solved = |answer: Answer, micros| {
+            Outcome::Solved(Solved {
+                answer,
+                parse: Duration::from_micros(micros),
+                compute: Duration::from_micros(micros),
+            })
+        };
+        let outcomes = vec![
+            (1, 1, solved(Answer::from(24000), 10)),
+            (5, 1, solved(Answer::from("CMZ"), 20)),
+            (7, 2, solved(Answer::screen("#.\n.#"), 30)),
+            (
+                8,
+                1,
+                Outcome::Failed("No solution: Empty forest".to_string()),
+            ),
+            (9, 2, Outcome::Panicked("attempt to overflow".to_string())),
+        ];
+        assert_eq!(
+            table(&outcomes, Duration::from_micros(100)),
+            "Day  Part       Time  Answer
+01      1     20.0µs  24000
+05      1     40.0µs  CMZ
+07      2     60.0µs  #.
+                      .#
+08      1          -  ERROR, No solution: Empty forest
+09      2          -  PANIC, attempt to overflow
+3 solved, 1 failed, 1 panicked, in 100.0µs (120.0µs solving)"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc_common::generate::Generate;
+use aoc_common::input::normalise;
+use aoc_common::variant::{self, Check};
+use aoc_common::{Answer, Params, Solution, SolveError};
+
+/// A day's `parse` and one of its parts, timed.
+pub type Solver = fn(&str, &Params) -> Result<Solved, SolveError>;
+
+/// A part's answer, and how long each half of solving it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub compute: Duration,
+}
+
+impl Solved {
+    pub fn total(&self) -> Duration {
+        self.parse + self.compute
+    }
+}
+
+/// A puzzle day and the solvers for both of its parts.
+#[derive(Debug)]
+pub struct Day {
+    pub number: u8,
+    /// Crate directory, relative to the workspace root.
+    pub dir: &'static str,
+    pub parts: [Solver; 2],
+    /// Names of the day's parameters, see [`Solution::PARAMS`].
+    pub params: &'static [&'static str],
+    /// Input made up from a seed and a size.
+    pub generate: fn(u64, usize) -> String,
+    /// Answers of a part from the day's solution and each of its variants.
+    pub check: fn(u8, &str, &Params) -> Result<Check, SolveError>,
+}
+
+impl Day {
+    /// The day's own `input.txt`.
+    pub fn input_path(&self) -> PathBuf {
+        aoc_common::input::default_path(self.path())
+    }
+
+    /// The day's `answers.toml`, the recorded answers for its `input.txt`.
+    pub fn answers_path(&self) -> PathBuf {
+        self.path().join("answers.toml")
+    }
+
+    /// The day's `params.toml`, its tunable puzzle parameters.
+    pub fn params_path(&self) -> PathBuf {
+        aoc_common::params::default_path(self.path())
+    }
+
+    /// The day's `example.txt`, the example from the puzzle description.
+    pub fn example_path(&self) -> PathBuf {
+        self.path().join("example.txt")
+    }
+
+    /// Solve a single part, numbered from 1.
+    pub fn solve(&self, part: u8, input: &str, params: &Params) -> Result<Solved, SolveError> {
+        (self.parts[usize::from(part) - 1])(input, params)
+    }
+
+    fn path(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.dir)
+    }
+}
+
+/// Solve a part of a day, numbered from 1, timing `parse` and the part separately.
+fn timed<S: Solution, const PART: u8>(input: &str, params: &Params) -> Result<Solved, SolveError> {
+    let start = Instant::now();
+    let input = normalise(input);
+    let parsed = S::parse(&input)?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    let answer = match PART {
+        1 => S::part_one_with(&parsed, params)?.into(),
+        _ => S::part_two_with(&parsed, params)?.into(),
+    };
+    let compute = start.elapsed();
+    Ok(Solved {
+        answer,
+        parse,
+        compute,
+    })
+}
+
+macro_rules! register {
+    ($($number:literal, $dir:literal => $krate:ident::$solution:ident),* $(,)?) => {
+        /// Every day in the workspace, in order.
+        pub const DAYS: &[Day] = &[
+            $(
+                Day {
+                    number: $number,
+                    dir: $dir,
+                    parts: [
+                        timed::<$krate::$solution, 1>,
+                        timed::<$krate::$solution, 2>,
+                    ],
+                    params: <$krate::$solution as Solution>::PARAMS,
+                    generate: <$krate::$solution as Generate>::seeded,
+                    check: variant::check::<$krate::$solution>,
+                },
+            )*
+        ];
+    };
+}
+
+/// Invoke a macro with every day in the workspace, as `number, "dir" => crate::Solution`.
+#[macro_export]
+macro_rules! with_days {
+    ($callback:ident) => {
+        $callback! {
+            1, "day-01" => day_01::Day01,
+            2, "day-02" => day_02::Day02,
+            3, "day-03" => day_03::Day03,
+            4, "day-04" => day_04::Day04,
+            5, "day-05" => day_05::Day05,
+            6, "day-06" => day_06::Day06,
+            7, "day-07" => day_07::Day07,
+            8, "day-08" => day_08::Day08,
+            9, "day-09" => day_09::Day09,
+            10, "day-10" => day_10::Day10,
+            11, "day-11" => day_11::Day11,
+            12, "day-12" => day_12::Day12,
+            13, "day-13" => day_13::Day13,
+            14, "day-14" => day_14::Day14,
+        }
+    };
+}
+
+with_days!(register);
+
+/// Look up a registered day.
+pub fn day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,97 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::registry::Solved;
+
+/// How long a single part took, for the `--time` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub parse: Duration,
+    pub compute: Duration,
+}
+
+impl Timing {
+    pub fn new(day: u8, part: u8, solved: &Solved) -> Self {
+        Self {
+            day,
+            part,
+            parse: solved.parse,
+            compute: solved.compute,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.compute
+    }
+}
+
+/// Table of parse and compute times, one row per part, followed by the overall total.
+pub fn table(timings: &[Timing]) -> String {
+    let mut table = format!(
+        "{:<4} {:>4} {:>10} {:>10} {:>10}\n",
+        "Day", "Part", "Parse", "Compute", "Total"
+    );
+    for timing in timings {
+        writeln!(
+            table,
+            "{:<4} {:>4} {:>10} {:>10} {:>10}",
+            format!("{:02}", timing.day),
+            timing.part,
+            duration(timing.parse),
+            duration(timing.compute),
+            duration(timing.total()),
+        )
+        .unwrap();
+    }
+    let total: Duration = timings.iter().map(Timing::total).sum();
+    write!(
+        table,
+        "{:<4} {:>4} {:>10} {:>10} {:>10}",
+        "All",
+        "",
+        "",
+        "",
+        duration(total)
+    )
+    .unwrap();
+    table
+}
+
+/// Duration in its most readable unit, to one decimal place.
+pub(crate) fn duration(duration: Duration) -> String {
+    format!("{:.1?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_table() {
+        let timings = vec![
+            Timing {
+                day: 1,
+                part: 1,
+                parse: Duration::from_micros(1500),
+                compute: Duration::from_micros(20),
+            },
+            Timing {
+                day: 12,
+                part: 2,
+                parse: Duration::from_micros(30),
+                compute: Duration::from_millis(250),
+            },
+        ];
+        assert_eq!(
+            table(&timings),
+            "Day  Part      Parse    Compute      Total
+01      1      1.5ms     20.0µs      1.5ms
+12      2     30.0µs    250.0ms    250.0ms
+All                                251.6ms"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use runner::answers;
+use runner::DAYS;
+
+#[test]
+fn test_recorded_answers() {
+    for day in DAYS {
+        for (part, verdict) in answers::verify(day, 1..=2) {
+            assert!(
+                !verdict.is_failure(),
+                "Day {:02} Part {}: {}",
+                day.number,
+                part,
+                verdict
+            );
+        }
+    }
+}
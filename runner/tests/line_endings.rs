@@ -0,0 +1,33 @@
+use std::fs;
+
+use aoc_common::Params;
+use runner::DAYS;
+
+#[test]
+fn test_examples_saved_elsewhere() {
+    for day in DAYS {
+        let example = fs::read_to_string(day.example_path()).unwrap();
+        let crlf = example.replace('\n', "\r\n");
+        let variants = [
+            format!("{}\r\n\r\n", crlf.trim_end()),
+            format!("\u{feff}{}", crlf),
+            format!("{}\n\n \n", example.trim_end()),
+            example.trim_end().to_string(),
+        ];
+        for part in 1..=2 {
+            let expected = day
+                .solve(part, &example, &Params::default())
+                .map(|solved| solved.answer);
+            for variant in &variants {
+                let answer = day
+                    .solve(part, variant, &Params::default())
+                    .map(|solved| solved.answer);
+                assert_eq!(
+                    answer, expected,
+                    "Day {:02} Part {}: {:?}",
+                    day.number, part, variant
+                );
+            }
+        }
+    }
+}